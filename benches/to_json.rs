@@ -0,0 +1,42 @@
+//! `ApiResp`序列化为JSON的性能对比：复制与借用业务数据，以及每次新建与重复使用输出缓冲区。
+use std::hint::black_box;
+
+use api_resp::ApiResp;
+use criterion::{criterion_group, criterion_main, Criterion};
+use serde_json::json;
+
+fn cached_data() -> serde_json::Value {
+    let list: Vec<_> = (0..200).map(|i| json!({"id": i, "name": format!("商品{}", i), "price": i as f64 * 1.5})).collect();
+    json!({"list": list, "total": 200, "page": 1, "size": 200})
+}
+
+fn bench_to_json(c: &mut Criterion) {
+    let cached = cached_data();
+    c.bench_function("success_clone_to_json", |b| {
+        b.iter(|| ApiResp::success(black_box(&cached).clone()).to_json())
+    });
+    c.bench_function("success_ref_to_json", |b| {
+        b.iter(|| ApiResp::success_ref(black_box(&cached)).to_json())
+    });
+}
+
+fn bench_to_json_into(c: &mut Criterion) {
+    let resp = ApiResp::fail(500, "交易出错了").with_trace_id("8f14e45f");
+    c.bench_function("to_json", |b| {
+        b.iter(|| black_box(&resp).to_json())
+    });
+    let mut buf = String::with_capacity(256);
+    c.bench_function("to_json_into_reused_buf", |b| {
+        b.iter(|| black_box(&resp).to_json_into(&mut buf))
+    });
+    let mut out = Vec::with_capacity(256);
+    c.bench_function("write_json_reused_vec", |b| {
+        b.iter(|| {
+            out.clear();
+            black_box(&resp).write_json(&mut out).unwrap()
+        })
+    });
+}
+
+criterion_group!(benches, bench_to_json, bench_to_json_into);
+criterion_main!(benches);
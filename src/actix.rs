@@ -0,0 +1,39 @@
+//! 为`actix-web` 4.x框架提供的响应集成，需启用`actix`特性。
+//!
+//! 启用后`ApiResp`可直接作为`actix-web`处理函数的返回值，响应体为JSON字符串，`Content-Type`为`application/json`。
+//! HTTP状态码默认为200，注册了[`CodeStatusMap`]时以对照表为准。
+use actix_web::body::BoxBody;
+use actix_web::http::header::ContentType;
+use actix_web::http::StatusCode;
+use actix_web::{HttpRequest, HttpResponse, Responder};
+
+use crate::{ApiResp, CodeStatusMap};
+
+impl Responder for ApiResp {
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+        let status = CodeStatusMap::installed()
+            .map_or(StatusCode::OK, |map| {
+                StatusCode::from_u16(map.status_of(&self)).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+            });
+        HttpResponse::build(status)
+            .content_type(ContentType::json())
+            .body(self.to_json())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::header::CONTENT_TYPE;
+    use actix_web::test::TestRequest;
+    use super::*;
+
+    #[test]
+    fn test_respond_to() {
+        let req = TestRequest::default().to_http_request();
+        let resp = ApiResp::error(-1, "交易出错了".to_string()).respond_to(&req);
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get(CONTENT_TYPE).unwrap(), "application/json");
+    }
+}
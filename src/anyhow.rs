@@ -0,0 +1,72 @@
+//! `anyhow`错误的转换支持，需启用`anyhow`特性。
+use serde::Serialize;
+
+use crate::{default_error_code, ApiResp};
+
+/// 将`anyhow`错误转换为失败响应，日志中记录完整的错误链。
+///
+/// # Examples
+///
+/// ```
+/// use anyhow::Context;
+/// use api_resp::ApiResp;
+///
+/// fn load() -> Result<(), ApiResp> {
+///     std::fs::read("/not/exists").context("读取配置失败")?;
+///     Ok(())
+/// }
+///
+/// let resp = load().unwrap_err();
+/// assert_eq!(resp.get_message(), "读取配置失败");
+/// ```
+impl From<anyhow::Error> for ApiResp {
+    fn from(e: anyhow::Error) -> Self {
+        crate::__log_error!("{:?}", e);
+        ApiResp::error(default_error_code(), e.to_string())
+    }
+}
+
+impl ApiResp {
+    /// 从`anyhow::Result`构造响应对象，成功时序列化业务数据，失败时记录错误链并返回失败响应。
+    ///
+    /// # Arguments
+    ///
+    /// * `r`: 业务处理结果。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::from_anyhow(Ok::<_, anyhow::Error>(vec![1, 2]));
+    /// assert!(resp.is_success());
+    ///
+    /// let resp = ApiResp::from_anyhow(Err::<i32, _>(anyhow::anyhow!("交易出错了")));
+    /// assert_eq!(resp.get_message(), "交易出错了");
+    /// ```
+    pub fn from_anyhow<T: Serialize>(r: anyhow::Result<T>) -> ApiResp {
+        match r {
+            Ok(t) => ApiResp::of(t),
+            Err(e) => e.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+    use super::*;
+
+    #[test]
+    fn test_from_anyhow() {
+        let r: anyhow::Result<i32> = Err(anyhow::anyhow!("连接数据库失败")).context("查询订单失败");
+        let resp = ApiResp::from_anyhow(r);
+        assert!(!resp.is_success());
+        assert_eq!(resp.get_code(), default_error_code());
+        assert_eq!(resp.get_message(), "查询订单失败");
+
+        let resp = ApiResp::from_anyhow(Ok::<_, anyhow::Error>("ok"));
+        assert_eq!(resp.get_data(), &Some(serde_json::json!("ok")));
+    }
+}
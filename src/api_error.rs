@@ -0,0 +1,69 @@
+//! 失败响应对应的错误类型，便于调用方以`?`处理远程接口的失败。
+use alloc::string::String;
+use core::fmt::{Display, Formatter};
+
+use crate::ApiResp;
+
+/// 远程接口返回的失败信息，携带失败响应的代码与提示信息。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    /// 失败代码
+    pub code: i32,
+    /// 失败提示信息
+    pub message: String,
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[{}] {}", self.code, self.message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ApiError {}
+
+impl ApiResp {
+    /// 将响应对象转换为`Result`，成功时返回业务数据，失败时返回携带代码与提示信息的[`ApiError`]。
+    ///
+    /// returns: `Result<Option<serde_json::Value>, ApiError>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::{ApiError, ApiResp};
+    /// use serde_json::json;
+    ///
+    /// fn call(body: &str) -> Result<i64, Box<dyn std::error::Error>> {
+    ///     let data = ApiResp::from_json(body)?.ok_or_err()?;
+    ///     Ok(data.and_then(|d| d["id"].as_i64()).unwrap_or_default())
+    /// }
+    ///
+    /// assert_eq!(call(ApiResp::success(json!({"id": 7})).to_json().as_str()).unwrap(), 7);
+    /// let e = call(ApiResp::error(404, String::from("未找到目标记录")).to_json().as_str()).unwrap_err();
+    /// assert_eq!(e.to_string(), "[404] 未找到目标记录");
+    /// ```
+    pub fn ok_or_err(mut self) -> Result<Option<serde_json::Value>, ApiError> {
+        if self.success {
+            Ok(self.take_data())
+        } else {
+            Err(ApiError { code: self.code, message: self.message })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_ok_or_err() {
+        assert_eq!(ApiResp::success(json!([1, 2])).ok_or_err(), Ok(Some(json!([1, 2]))));
+        assert_eq!(ApiResp::suc().ok_or_err(), Ok(None));
+        assert_eq!(
+            ApiResp::error(500, "交易出错了".to_string()).ok_or_err(),
+            Err(ApiError { code: 500, message: "交易出错了".to_string() })
+        );
+    }
+}
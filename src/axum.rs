@@ -0,0 +1,103 @@
+//! 为`axum`框架提供的响应集成，需启用`axum`特性。
+//!
+//! 启用后`ApiResp`可直接作为`axum`处理函数的返回值，响应体为JSON字符串，`Content-Type`为`application/json`。
+use core::sync::atomic::{AtomicU16, Ordering};
+
+use axum::http::{header, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+
+use crate::{default_error_code, ApiResp, CodeStatusMap, DaoResult};
+
+/// 失败响应使用的HTTP状态码，默认为200，因为响应体中的`success`已经表明了执行结果。
+/// 注册了[`CodeStatusMap`]时，以对照表为准。
+static FAILURE_STATUS: AtomicU16 = AtomicU16::new(200);
+
+/// 设置失败响应使用的HTTP状态码，成功响应总是使用200。
+///
+/// # Arguments
+///
+/// * `status`: 失败响应的HTTP状态码。
+///
+/// # Examples
+///
+/// ```
+/// use axum::http::StatusCode;
+/// api_resp::axum::set_failure_status(StatusCode::INTERNAL_SERVER_ERROR);
+/// ```
+pub fn set_failure_status(status: StatusCode) {
+    FAILURE_STATUS.store(status.as_u16(), Ordering::Relaxed);
+}
+
+fn failure_status() -> StatusCode {
+    StatusCode::from_u16(FAILURE_STATUS.load(Ordering::Relaxed)).unwrap_or(StatusCode::OK)
+}
+
+impl IntoResponse for ApiResp {
+    fn into_response(self) -> Response {
+        let status = match CodeStatusMap::installed() {
+            Some(map) => StatusCode::from_u16(map.status_of(&self)).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            None if self.success => StatusCode::OK,
+            None => failure_status(),
+        };
+        (
+            status,
+            [(header::CONTENT_TYPE, HeaderValue::from_static("application/json"))],
+            self.to_json(),
+        ).into_response()
+    }
+}
+
+/// `DaoResult`的响应包装。
+///
+/// 受孤儿规则限制，无法直接为`DaoResult`实现`IntoResponse`，处理函数可返回该包装类型，
+/// 出错时记录日志并返回失败响应，与`TransformResult::to_json_str`的处理方式一致。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::{ApiResp, DaoResult};
+/// use api_resp::axum::DaoResponse;
+///
+/// async fn query() -> DaoResult {
+///     Ok(ApiResp::suc())
+/// }
+///
+/// async fn handler() -> DaoResponse {
+///     query().await.into()
+/// }
+/// ```
+pub struct DaoResponse(pub DaoResult);
+
+impl From<DaoResult> for DaoResponse {
+    fn from(r: DaoResult) -> Self {
+        DaoResponse(r)
+    }
+}
+
+impl IntoResponse for DaoResponse {
+    fn into_response(self) -> Response {
+        let ret: ApiResp = match self.0 {
+            Ok(r) => r,
+            Err(e) => {
+                crate::__log_error!("{:?}", e);
+                ApiResp::error(default_error_code(), e.to_string())
+            }
+        };
+        ret.into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_response() {
+        let resp = ApiResp::suc().into_response();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers()[header::CONTENT_TYPE], "application/json");
+
+        let resp = DaoResponse(Err("交易出错了".into())).into_response();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+}
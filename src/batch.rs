@@ -0,0 +1,116 @@
+//! 批量处理的响应数据结构。
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Serialize, Deserialize};
+
+use crate::to_json_or_error;
+
+/// 批量处理中单条数据的处理结果。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct ItemResult {
+    /// 数据在批次中的序号，从0开始。
+    pub index: usize,
+    /// 处理是否成功。
+    pub success: bool,
+    /// 处理附带消息，通常是失败原因。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl ItemResult {
+    /// 构造一条处理成功的结果。
+    pub fn ok(index: usize) -> ItemResult {
+        ItemResult {
+            index,
+            success: true,
+            message: None,
+        }
+    }
+
+    /// 构造一条处理失败的结果。
+    pub fn fail(index: usize, message: impl Into<String>) -> ItemResult {
+        ItemResult {
+            index,
+            success: false,
+            message: Some(message.into()),
+        }
+    }
+}
+
+/// 批量处理的响应数据结构，用于表达部分成功的处理结果。
+///
+/// 序列化结构示例：
+/// ```json
+/// {"total":2,"succeeded":1,"failed":1,"results":[{"index":0,"success":true},{"index":1,"success":false,"message":"格式错误"}]}
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct BatchApiResp {
+    /// 总数据条数。
+    total: usize,
+    /// 处理成功的条数。
+    succeeded: usize,
+    /// 处理失败的条数。
+    failed: usize,
+    /// 每条数据的处理结果。
+    results: Vec<ItemResult>,
+}
+
+impl BatchApiResp {
+    /// 根据每条数据的处理结果构造批量处理的响应对象，自动统计成功与失败的条数。
+    ///
+    /// # Arguments
+    ///
+    /// * `results`: 每条数据的处理结果。
+    ///
+    /// returns: BatchApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::{BatchApiResp, ItemResult};
+    /// let resp = BatchApiResp::new(vec![ItemResult::ok(0), ItemResult::fail(1, "格式错误")]);
+    /// assert_eq!(resp.get_succeeded(), 1);
+    /// assert_eq!(resp.get_failed(), 1);
+    /// ```
+    pub fn new(results: Vec<ItemResult>) -> BatchApiResp {
+        let succeeded = results.iter().filter(|r| r.success).count();
+        BatchApiResp {
+            total: results.len(),
+            succeeded,
+            failed: results.len() - succeeded,
+            results,
+        }
+    }
+
+    pub fn get_total(&self) -> usize { self.total }
+
+    pub fn get_succeeded(&self) -> usize { self.succeeded }
+
+    pub fn get_failed(&self) -> usize { self.failed }
+
+    pub fn get_results(&self) -> &Vec<ItemResult> { &self.results }
+
+    /// 是否全部处理成功。
+    pub fn is_all_success(&self) -> bool { self.failed == 0 }
+
+    pub fn to_json(&self) -> String {
+        to_json_or_error(self, None, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_resp() {
+        let resp = BatchApiResp::new(vec![ItemResult::ok(0), ItemResult::fail(1, "格式错误")]);
+        let batch_json = resp.to_json();
+        assert_eq!(batch_json, r#"{"total":2,"succeeded":1,"failed":1,"results":[{"index":0,"success":true},{"index":1,"success":false,"message":"格式错误"}]}"#);
+        let orig: BatchApiResp = serde_json::from_str(batch_json.as_str()).unwrap();
+        assert_eq!(orig, resp);
+        assert!(!orig.is_all_success());
+    }
+}
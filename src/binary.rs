@@ -0,0 +1,63 @@
+//! 以base64编码携带文件内容的响应，需启用`base64`特性。
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use base64::prelude::{Engine, BASE64_STANDARD};
+use serde_json::json;
+
+use crate::ApiResp;
+
+impl ApiResp {
+    /// 构造一个携带二进制内容的成功响应，内容以base64编码保存在`data`中。
+    ///
+    /// 序列化结构示例：
+    /// ```json
+    /// {"mime": "text/plain", "base64": "aGVsbG8="}
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `mime`: 内容的MIME类型。
+    /// * `bytes`: 二进制内容。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::binary("text/plain", b"hello");
+    /// assert_eq!(resp.get_binary(), Some(("text/plain".to_string(), b"hello".to_vec())));
+    /// ```
+    pub fn binary(mime: &str, bytes: &[u8]) -> ApiResp {
+        ApiResp::success(json!({
+            "mime": mime,
+            "base64": BASE64_STANDARD.encode(bytes),
+        }))
+    }
+
+    /// 获取`binary`构造的二进制内容。
+    ///
+    /// returns: `Option<(String, Vec<u8>)>` MIME类型与解码后的内容，业务数据不符合结构或无法解码时返回`None`。
+    pub fn get_binary(&self) -> Option<(String, Vec<u8>)> {
+        let data = self.data.as_ref()?;
+        let mime = data.get("mime")?.as_str()?;
+        let bytes = BASE64_STANDARD.decode(data.get("base64")?.as_str()?).ok()?;
+        Some((mime.to_string(), bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let resp = ApiResp::binary("application/octet-stream", &bytes);
+        let back = ApiResp::from_json(resp.to_json().as_str()).unwrap();
+        assert_eq!(back.get_binary(), Some(("application/octet-stream".to_string(), bytes)));
+
+        assert_eq!(ApiResp::success(json!({"mime": "text/plain", "base64": "!!"})).get_binary(), None);
+        assert_eq!(ApiResp::suc().get_binary(), None);
+    }
+}
@@ -0,0 +1,84 @@
+//! 借用业务数据的API接口响应数据结构，用于避免复制已缓存的数据。
+use alloc::string::String;
+use serde::Serialize;
+
+use crate::{default_success_code, to_json_or_error, ApiResp};
+
+/// 借用业务数据的成功响应，序列化结果与[`ApiResp::success`]完全一致。
+///
+/// 适合反复输出同一份已缓存的`serde_json::Value`的场景，序列化时直接读取借用的数据，不产生复制。
+/// 该类型仅用于输出，不会调用通过`set_response_hook`注册的钩子。
+#[derive(Debug, Clone, Copy, Serialize)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct ApiRespRef<'a> {
+    /// 执行是否成功
+    #[cfg_attr(feature = "camel-case", serde(rename = "isSuccess"))]
+    #[cfg_attr(feature = "success-as-int", serde(serialize_with = "crate::serde_ext::serialize_success"))]
+    success: bool,
+    /// 响应代码
+    code: i32,
+    /// 响应附带消息。
+    message: &'a str,
+    /// 借用的响应数据。
+    data: Option<&'a serde_json::Value>,
+}
+
+impl<'a> ApiRespRef<'a> {
+    pub fn get_data(&self) -> Option<&'a serde_json::Value> { self.data }
+
+    pub fn to_json(&self) -> String {
+        to_json_or_error(self, Some(self.code), Some(self.success))
+    }
+
+    /// 转换为拥有数据的响应对象，此时才复制业务数据。
+    ///
+    /// returns: ApiResp
+    pub fn into_owned(self) -> ApiResp {
+        match self.data {
+            Some(d) => ApiResp::success(d.clone()),
+            None => ApiResp::suc(),
+        }
+    }
+}
+
+impl ApiResp {
+    /// 构造一个借用业务数据的成功响应，输出时不复制业务数据。
+    ///
+    /// # Arguments
+    ///
+    /// * `data`: 借用的业务数据。
+    ///
+    /// returns: ApiRespRef
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let cached = json!({"id": 1});
+    /// assert_eq!(ApiResp::success_ref(&cached).to_json(), ApiResp::success(cached.clone()).to_json());
+    /// ```
+    pub fn success_ref(data: &serde_json::Value) -> ApiRespRef<'_> {
+        ApiRespRef {
+            success: true,
+            code: default_success_code(),
+            message: "",
+            data: Some(data),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_success_ref() {
+        let cached = json!({"list": [1, 2, 3], "total": 3});
+        let resp = ApiResp::success_ref(&cached);
+        assert_eq!(resp.to_json(), ApiResp::success(cached.clone()).to_json());
+        assert_eq!(resp.into_owned(), ApiResp::success(cached.clone()));
+        assert!(core::ptr::eq(resp.get_data().unwrap(), &cached));
+    }
+}
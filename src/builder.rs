@@ -0,0 +1,78 @@
+//! API接口响应数据结构的构造器。
+use alloc::string::{String, ToString};
+
+use crate::{default_success_code, ApiResp};
+
+/// [`ApiResp`]的构造器，用于组合任意的响应属性。
+///
+/// 默认值为成功响应：`success=true, code=0, message="", data=None`，其中`code`取自[`default_success_code`](crate::default_success_code)。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::ApiRespBuilder;
+/// use serde_json::json;
+/// let resp = ApiRespBuilder::default()
+///     .message("保存成功")
+///     .data(json!({"id": 1}))
+///     .build();
+/// assert!(resp.is_success());
+/// assert_eq!(resp.get_message(), "保存成功");
+/// ```
+#[derive(Debug)]
+pub struct ApiRespBuilder {
+    success: bool,
+    code: i32,
+    message: String,
+    data: Option<serde_json::Value>,
+}
+
+impl Default for ApiRespBuilder {
+    fn default() -> Self {
+        ApiRespBuilder {
+            success: true,
+            code: default_success_code(),
+            message: "".to_string(),
+            data: None,
+        }
+    }
+}
+
+impl ApiRespBuilder {
+    /// 设置执行是否成功。
+    pub fn success(mut self, success: bool) -> ApiRespBuilder {
+        self.success = success;
+        self
+    }
+
+    /// 设置响应代码。
+    pub fn code(mut self, code: i32) -> ApiRespBuilder {
+        self.code = code;
+        self
+    }
+
+    /// 设置响应附带消息。
+    pub fn message(mut self, message: impl Into<String>) -> ApiRespBuilder {
+        self.message = message.into();
+        self
+    }
+
+    /// 设置响应数据。
+    pub fn data(mut self, data: serde_json::Value) -> ApiRespBuilder {
+        self.data = Some(data);
+        self
+    }
+
+    /// 构造响应对象。
+    ///
+    /// returns: ApiResp
+    pub fn build(self) -> ApiResp {
+        ApiResp {
+            success: self.success,
+            code: self.code,
+            message: self.message,
+            data: self.data,
+            ..ApiResp::default()
+        }
+    }
+}
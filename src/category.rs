@@ -0,0 +1,177 @@
+//! 响应代码的分类与一致性检查。
+use core::ops::RangeInclusive;
+
+use crate::{default_error_code, default_success_code, ApiResp};
+
+/// 响应代码的类别。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodeCategory {
+    /// 成功。
+    Success,
+    /// 调用方错误。
+    ClientError,
+    /// 服务端错误。
+    ServerError,
+    /// 不属于任何已知范围。
+    Unknown,
+}
+
+/// 各类别对应的响应代码范围。
+///
+/// 可以基于[`CodeRanges::DEFAULT`]定义自己的常量，覆盖部分或全部范围。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::{ApiResp, CodeCategory, CodeRanges};
+///
+/// const MY_RANGES: CodeRanges = CodeRanges {
+///     client_error: 1000..=1999,
+///     ..CodeRanges::DEFAULT
+/// };
+///
+/// let resp = ApiResp::fail(1001, "余额不足");
+/// assert_eq!(resp.classify_with(&MY_RANGES), CodeCategory::ClientError);
+/// assert_eq!(resp.classify(), CodeCategory::Unknown);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeRanges {
+    /// 成功的代码范围。
+    pub success: RangeInclusive<i32>,
+    /// 调用方错误的代码范围。
+    pub client_error: RangeInclusive<i32>,
+    /// 服务端错误的代码范围。
+    pub server_error: RangeInclusive<i32>,
+}
+
+impl CodeRanges {
+    /// 默认的代码范围：`0`为成功，`400`至`499`为调用方错误，`500`至`599`为服务端错误。
+    pub const DEFAULT: CodeRanges = CodeRanges {
+        success: 0..=0,
+        client_error: 400..=499,
+        server_error: 500..=599,
+    };
+
+    /// 获取响应代码的类别。
+    ///
+    /// # Arguments
+    ///
+    /// * `code`: 响应代码。
+    ///
+    /// returns: CodeCategory
+    pub fn classify(&self, code: i32) -> CodeCategory {
+        if self.success.contains(&code) {
+            CodeCategory::Success
+        } else if self.client_error.contains(&code) {
+            CodeCategory::ClientError
+        } else if self.server_error.contains(&code) {
+            CodeCategory::ServerError
+        } else {
+            CodeCategory::Unknown
+        }
+    }
+}
+
+impl Default for CodeRanges {
+    fn default() -> Self {
+        CodeRanges::DEFAULT
+    }
+}
+
+impl ApiResp {
+    /// 按默认的代码范围获取响应的类别，其中成功的代码范围以[`default_success_code`]为准，分类规则见[`classify_with`](ApiResp::classify_with)。
+    ///
+    /// returns: CodeCategory
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::{ApiResp, CodeCategory};
+    /// assert_eq!(ApiResp::suc().classify(), CodeCategory::Success);
+    /// assert_eq!(ApiResp::error(404, String::from("未找到目标记录")).classify(), CodeCategory::ClientError);
+    /// ```
+    pub fn classify(&self) -> CodeCategory {
+        let success_code = default_success_code();
+        self.classify_with(&CodeRanges {
+            success: success_code..=success_code,
+            ..CodeRanges::DEFAULT
+        })
+    }
+
+    /// 按指定的代码范围获取响应的类别。
+    ///
+    /// 成功响应总是归为[`CodeCategory::Success`]；失败响应按代码范围分类，代码落在成功范围内时归为[`CodeCategory::Unknown`]，
+    /// 失败响应不会归为成功。不在任何范围内的[`default_error_code`]归为[`CodeCategory::ServerError`]。
+    ///
+    /// # Arguments
+    ///
+    /// * `ranges`: 代码范围。
+    ///
+    /// returns: CodeCategory
+    pub fn classify_with(&self, ranges: &CodeRanges) -> CodeCategory {
+        if self.success {
+            return CodeCategory::Success;
+        }
+        match ranges.classify(self.code) {
+            CodeCategory::Success => CodeCategory::Unknown,
+            CodeCategory::Unknown if self.code == default_error_code() => CodeCategory::ServerError,
+            category => category,
+        }
+    }
+
+    /// 按默认的代码范围检查响应的各属性是否相互矛盾，规则见[`is_consistent_with`](ApiResp::is_consistent_with)。
+    ///
+    /// returns: bool
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::{ApiResp, ApiRespBuilder};
+    /// assert!(ApiResp::suc().is_consistent());
+    /// assert!(!ApiRespBuilder::default().code(-1).build().is_consistent());
+    /// assert!(!ApiResp::error(500, String::new()).is_consistent());
+    /// ```
+    pub fn is_consistent(&self) -> bool {
+        self.is_consistent_with(&CodeRanges::DEFAULT)
+    }
+
+    /// 按指定的代码范围检查响应的各属性是否相互矛盾。
+    ///
+    /// 检查的规则：
+    /// - 成功响应的代码等于[`default_success_code`]，或者在成功的代码范围内；
+    /// - 失败响应的代码不等于成功响应的代码，也不在成功的代码范围内；
+    /// - 失败响应的提示信息不为空。
+    ///
+    /// # Arguments
+    ///
+    /// * `ranges`: 代码范围。
+    ///
+    /// returns: bool
+    pub fn is_consistent_with(&self, ranges: &CodeRanges) -> bool {
+        let success_code = self.code == default_success_code() || ranges.success.contains(&self.code);
+        if self.success {
+            success_code
+        } else {
+            !success_code && !self.message.is_empty()
+        }
+    }
+
+    /// 按默认的代码范围检查响应的各属性是否相互矛盾，不一致时在调试构建中触发panic，发布构建中不做任何检查。
+    pub fn debug_assert_consistent(&self) {
+        debug_assert!(self.is_consistent(), "响应的属性相互矛盾：{}", self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_by_success_flag() {
+        assert_eq!(ApiResp::fail(0, "代码与结果不一致").classify(), CodeCategory::Unknown);
+        assert_eq!(ApiResp::success_coded(201, serde_json::json!(1)).classify(), CodeCategory::Success);
+        assert_eq!(ApiResp::fail(503, "服务不可用").classify(), CodeCategory::ServerError);
+        assert_eq!(ApiResp::error(-1, "交易出错了".to_string()).classify(), CodeCategory::ServerError);
+        assert_eq!(ApiResp::fail(-2, "未知错误").classify(), CodeCategory::Unknown);
+    }
+}
@@ -0,0 +1,92 @@
+//! 带有结构化错误明细的失败响应。
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Serialize, Deserialize};
+
+use crate::ApiResp;
+
+/// 一条错误明细。
+///
+/// 失败响应的`data`序列化结构示例：
+/// ```json
+/// {"details": [{"field": "email", "code": "invalid_format", "message": "邮箱格式不正确"}]}
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorDetail {
+    /// 出错的字段名，与具体字段无关时为空。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    /// 细分的错误代码，与响应代码相互独立。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// 错误描述。
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ErrorDetails {
+    details: Vec<ErrorDetail>,
+}
+
+impl ApiResp {
+    /// 构造一个带有错误明细的失败响应对象，明细列表保存在`data`的`details`属性中。
+    ///
+    /// # Arguments
+    ///
+    /// * `code`: 失败代码。
+    /// * `message`: 失败信息。
+    /// * `details`: 错误明细列表。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::{ApiResp, ErrorDetail};
+    /// let resp = ApiResp::error_details(400, String::from("请求参数有误"), vec![ErrorDetail {
+    ///     field: Some("email".to_string()),
+    ///     code: Some("invalid_format".to_string()),
+    ///     message: "邮箱格式不正确".to_string(),
+    /// }]);
+    /// assert!(!resp.is_success());
+    /// assert_eq!(resp.get_error_details().unwrap()[0].message, "邮箱格式不正确");
+    /// ```
+    pub fn error_details(code: i32, message: String, details: Vec<ErrorDetail>) -> ApiResp {
+        let mut resp = ApiResp::error(code, message);
+        resp.data = serde_json::to_value(ErrorDetails { details }).ok();
+        resp
+    }
+
+    /// 获取错误明细列表。
+    ///
+    /// returns: `Option<Vec<ErrorDetail>>` 业务数据中没有符合结构的错误明细时返回`None`。
+    pub fn get_error_details(&self) -> Option<Vec<ErrorDetail>> {
+        self.get_data_as::<ErrorDetails>().ok().flatten().map(|d| d.details)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_error_details() {
+        let details = vec![
+            ErrorDetail { field: Some("email".to_string()), code: None, message: "邮箱格式不正确".to_string() },
+            ErrorDetail { field: None, code: Some("quota".to_string()), message: "超出配额".to_string() },
+        ];
+        let resp = ApiResp::error_details(400, "请求参数有误".to_string(), details.clone());
+        assert_eq!(resp.get_data(), &Some(json!({"details": [
+            {"field": "email", "message": "邮箱格式不正确"},
+            {"code": "quota", "message": "超出配额"},
+        ]})));
+        let back = ApiResp::from_json(resp.to_json().as_str()).unwrap();
+        assert_eq!(back.get_error_details(), Some(details));
+
+        assert_eq!(ApiResp::success(json!([1])).get_error_details(), None);
+        assert_eq!(ApiResp::fail(-1, "交易出错了").get_error_details(), None);
+    }
+}
@@ -0,0 +1,98 @@
+//! 业务错误代码的特性声明。
+use alloc::string::String;
+
+use crate::ApiResp;
+
+/// 业务错误代码，为错误提供稳定的代码和可复用的提示信息。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::{ApiResp, ErrorCode};
+///
+/// enum BizError {
+///     NotFound,
+///     Unauthorized,
+/// }
+///
+/// impl ErrorCode for BizError {
+///     fn code(&self) -> i32 {
+///         match self {
+///             BizError::NotFound => 404,
+///             BizError::Unauthorized => 401,
+///         }
+///     }
+///
+///     fn message(&self) -> String {
+///         match self {
+///             BizError::NotFound => "未找到目标记录".to_string(),
+///             BizError::Unauthorized => "未授权的访问".to_string(),
+///         }
+///     }
+/// }
+///
+/// let resp = ApiResp::error_from(BizError::NotFound);
+/// assert_eq!(resp.get_code(), 404);
+/// ```
+pub trait ErrorCode {
+    /// 错误代码。
+    fn code(&self) -> i32;
+
+    /// 错误提示信息。
+    fn message(&self) -> String;
+}
+
+impl ApiResp {
+    /// 根据业务错误代码构造一个失败的响应对象。
+    ///
+    /// # Arguments
+    ///
+    /// * `e`: 业务错误代码。
+    ///
+    /// returns: ApiResp
+    pub fn error_from(e: impl ErrorCode) -> ApiResp {
+        ApiResp::error(e.code(), e.message())
+    }
+}
+
+/// 为枚举批量实现[`ErrorCode`]，免去手写`match`分支。
+///
+/// 每个变体声明为`变体 => (代码, 提示信息)`，仅支持不带数据的枚举变体。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::{impl_error_code, ApiResp, ErrorCode};
+///
+/// enum BizError {
+///     NotFound,
+///     Unauthorized,
+/// }
+///
+/// impl_error_code!(BizError {
+///     NotFound => (404, "未找到目标记录"),
+///     Unauthorized => (401, "未授权的访问"),
+/// });
+///
+/// let resp = ApiResp::error_from(BizError::Unauthorized);
+/// assert_eq!(resp.get_code(), 401);
+/// assert_eq!(resp.get_message(), "未授权的访问");
+/// ```
+#[macro_export]
+macro_rules! impl_error_code {
+    ($name: ident { $($variant: ident => ($code: expr, $msg: expr)),+ $(,)? }) => {
+        impl $crate::ErrorCode for $name {
+            fn code(&self) -> i32 {
+                match self {
+                    $($name::$variant => $code,)+
+                }
+            }
+
+            fn message(&self) -> $crate::__String {
+                match self {
+                    $($name::$variant => $crate::__String::from($msg),)+
+                }
+            }
+        }
+    };
+}
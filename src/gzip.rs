@@ -0,0 +1,62 @@
+//! gzip压缩的JSON格式支持，需启用`flate2`特性。
+//!
+//! 用于服务之间传输或缓存较大的响应，HTTP层面的压缩请交由Web框架处理。
+use std::io::{self, Read, Write};
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use crate::ApiResp;
+
+impl ApiResp {
+    /// 将响应对象序列化为JSON并进行gzip压缩。
+    ///
+    /// returns: `io::Result<Vec<u8>>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let resp = ApiResp::success(json!(vec![1,1,3,5]));
+    /// let bytes = resp.to_json_gzip().unwrap();
+    /// assert_eq!(ApiResp::from_json_gzip(&bytes).unwrap(), resp);
+    /// ```
+    pub fn to_json_gzip(&self) -> io::Result<Vec<u8>> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        serde_json::to_writer(&mut encoder, self)?;
+        encoder.flush()?;
+        encoder.finish()
+    }
+
+    /// 解压gzip压缩的JSON数据，并反序列化为响应对象。
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes`: gzip压缩的JSON数据。
+    ///
+    /// returns: `io::Result<ApiResp>` 数据无法解压或不是合法的响应结构时返回错误。
+    pub fn from_json_gzip(bytes: &[u8]) -> io::Result<ApiResp> {
+        let mut json = Vec::new();
+        GzDecoder::new(bytes).read_to_end(&mut json)?;
+        Ok(serde_json::from_slice(&json)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_json_gzip() {
+        let rows: Vec<_> = (0..10_000).map(|i| json!({"id": i, "color": "white", "weight": 10.5})).collect();
+        let resp = ApiResp::success(json!(rows));
+        let bytes = resp.to_json_gzip().unwrap();
+        assert!(bytes.len() < resp.to_json().len() / 10);
+        assert_eq!(ApiResp::from_json_gzip(&bytes).unwrap(), resp);
+
+        assert!(ApiResp::from_json_gzip(b"not gzip").is_err());
+    }
+}
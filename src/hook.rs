@@ -0,0 +1,108 @@
+//! 响应对象的全局钩子，用于指标统计、审计日志等横切关注点。
+use std::boxed::Box;
+use std::sync::OnceLock;
+
+use crate::ApiResp;
+
+/// 响应钩子，在响应对象序列化为JSON字符串之前调用。
+///
+/// 钩子可能在多个线程中同时调用，实现时应避免耗时操作。
+pub trait ResponseHook: Send + Sync {
+    /// 响应对象即将输出。
+    ///
+    /// # Arguments
+    ///
+    /// * `resp`: 即将序列化的响应对象。
+    fn on_response(&self, resp: &ApiResp);
+}
+
+impl<F: Fn(&ApiResp) + Send + Sync> ResponseHook for F {
+    fn on_response(&self, resp: &ApiResp) {
+        self(resp)
+    }
+}
+
+static RESPONSE_HOOK: OnceLock<Box<dyn ResponseHook>> = OnceLock::new();
+
+/// 注册全局的响应钩子，只能注册一次，未注册时不做任何处理。
+///
+/// # Arguments
+///
+/// * `hook`: 响应钩子，可以是实现了[`ResponseHook`]的类型或闭包。
+///
+/// returns: `Result<(), Box<dyn ResponseHook>>` 已经注册过时返回`Err`，携带本次传入的钩子。
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use api_resp::ApiResp;
+///
+/// static FAILURES: AtomicUsize = AtomicUsize::new(0);
+///
+/// api_resp::set_response_hook(|resp: &ApiResp| {
+///     if !resp.is_success() {
+///         FAILURES.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }).ok();
+///
+/// ApiResp::error(-1, "交易出错了".to_string()).to_json();
+/// assert_eq!(FAILURES.load(Ordering::Relaxed), 1);
+/// ```
+pub fn set_response_hook(hook: impl ResponseHook + 'static) -> Result<(), Box<dyn ResponseHook>> {
+    RESPONSE_HOOK.set(Box::new(hook))
+}
+
+/// 调用已注册的响应钩子。
+pub(crate) fn on_response(resp: &ApiResp) {
+    if let Some(hook) = RESPONSE_HOOK.get() {
+        hook.on_response(resp);
+    }
+}
+
+/// 调用已注册的响应钩子，响应对象只在注册了钩子时才构造，用于需要转换才能得到[`ApiResp`]的类型。
+pub(crate) fn on_response_with(resp: impl FnOnce() -> ApiResp) {
+    if let Some(hook) = RESPONSE_HOOK.get() {
+        hook.on_response(&resp());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use crate::TransformResult;
+    use super::*;
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    struct CountingHook;
+
+    impl ResponseHook for CountingHook {
+        fn on_response(&self, _resp: &ApiResp) {
+            CALLS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn test_response_hook() {
+        assert!(set_response_hook(CountingHook).is_ok());
+        assert!(set_response_hook(|_: &ApiResp| {}).is_err());
+
+        let before = CALLS.load(Ordering::Relaxed);
+        ApiResp::suc().to_json();
+        assert!(CALLS.load(Ordering::Relaxed) > before);
+
+        let before = CALLS.load(Ordering::Relaxed);
+        let r: crate::DaoResult = Err("交易出错了".into());
+        r.to_json_str("交易出错");
+        assert!(CALLS.load(Ordering::Relaxed) > before);
+
+        let before = CALLS.load(Ordering::Relaxed);
+        Ok::<_, &str>(ApiResp::suc()).try_to_json_str("查询出错").unwrap();
+        assert!(CALLS.load(Ordering::Relaxed) > before);
+
+        let before = CALLS.load(Ordering::Relaxed);
+        crate::TypedApiResp::success(1).to_json();
+        assert!(CALLS.load(Ordering::Relaxed) > before);
+    }
+}
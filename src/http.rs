@@ -0,0 +1,64 @@
+//! 与框架无关的`http` 1.x响应转换，需启用`http`特性。
+//!
+//! 基于`http`库构建的Web框架，可以在此基础上适配`ApiResp`。
+use http::header::{HeaderValue, CONTENT_TYPE};
+use http::{Response, StatusCode};
+
+use crate::ApiResp;
+
+impl ApiResp {
+    /// 转换为`http::Response`，响应体为JSON字符串的字节，`Content-Type`为`application/json`，状态码为200。
+    ///
+    /// returns: `Response<Vec<u8>>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::suc().into_http_response();
+    /// assert_eq!(resp.status(), http::StatusCode::OK);
+    /// assert_eq!(resp.headers()[http::header::CONTENT_TYPE], "application/json");
+    /// ```
+    pub fn into_http_response(self) -> Response<Vec<u8>> {
+        let mut res = Response::new(self.to_json().into_bytes());
+        *res.status_mut() = StatusCode::OK;
+        res.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        res
+    }
+
+    /// 根据上游服务的HTTP状态码构造响应对象，`code`取状态码的数值，状态码为2xx时为成功响应。
+    ///
+    /// # Arguments
+    ///
+    /// * `status`: 上游服务的HTTP状态码。
+    /// * `message`: 响应附带消息。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use http::StatusCode;
+    /// let resp = ApiResp::from_status(StatusCode::NOT_FOUND, "未找到目标记录");
+    /// assert!(!resp.is_success());
+    /// assert_eq!(resp.get_code(), 404);
+    ///
+    /// let resp = ApiResp::from_status(StatusCode::CREATED, "");
+    /// assert!(resp.is_success() && resp.has_code(201));
+    /// ```
+    pub fn from_status(status: StatusCode, message: impl Into<String>) -> ApiResp {
+        ApiResp {
+            success: status.is_success(),
+            code: i32::from(status.as_u16()),
+            message: message.into(),
+            ..ApiResp::default()
+        }
+    }
+}
+
+impl From<ApiResp> for Response<Vec<u8>> {
+    fn from(resp: ApiResp) -> Self {
+        resp.into_http_response()
+    }
+}
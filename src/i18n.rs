@@ -0,0 +1,50 @@
+//! 提示信息本地化的特性声明。
+use alloc::string::String;
+
+use crate::ApiResp;
+
+/// 提示信息的本地化解析器，由调用方接入具体的国际化实现。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::{ApiResp, MessageResolver};
+///
+/// struct Messages;
+///
+/// impl MessageResolver for Messages {
+///     fn resolve(&self, code: i32, locale: &str) -> Option<String> {
+///         match (code, locale) {
+///             (404, "zh-CN") => Some("未找到目标记录".to_string()),
+///             (404, "en") => Some("Not found".to_string()),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let resp = ApiResp::error_localized(404, "en", &Messages, "未找到目标记录");
+/// assert_eq!(resp.get_message(), "Not found");
+/// let resp = ApiResp::error_localized(404, "fr", &Messages, "未找到目标记录");
+/// assert_eq!(resp.get_message(), "未找到目标记录");
+/// ```
+pub trait MessageResolver {
+    /// 根据错误代码和语言区域解析提示信息，无法解析时返回`None`。
+    fn resolve(&self, code: i32, locale: &str) -> Option<String>;
+}
+
+impl ApiResp {
+    /// 构造一个失败的响应对象，提示信息按语言区域本地化。
+    ///
+    /// # Arguments
+    ///
+    /// * `code`: 失败代码。
+    /// * `locale`: 语言区域，通常取自请求头`Accept-Language`。
+    /// * `resolver`: 提示信息的本地化解析器。
+    /// * `default_message`: 无法解析时使用的默认提示信息。
+    ///
+    /// returns: ApiResp
+    pub fn error_localized<R: MessageResolver + ?Sized>(code: i32, locale: &str, resolver: &R, default_message: impl Into<String>) -> ApiResp {
+        let message = resolver.resolve(code, locale).unwrap_or_else(|| default_message.into());
+        ApiResp::error(code, message)
+    }
+}
@@ -0,0 +1,105 @@
+//! JSON:API风格的顶层响应结构。
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use serde::{Serialize, Deserialize};
+
+use crate::ApiResp;
+
+/// JSON:API规范的媒体类型，输出[`JsonApiResp`]时应作为`Content-Type`。
+pub const JSON_API_CONTENT_TYPE: &str = "application/vnd.api+json";
+
+/// JSON:API的错误对象。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonApiError {
+    /// 错误代码，按规范以字符串表示。
+    pub code: String,
+    /// 错误描述。
+    pub detail: String,
+}
+
+/// JSON:API风格的响应结构，顶层为`data`、`errors`、`meta`。
+///
+/// 可由[`ApiResp`]转换而来：成功响应的业务数据映射为`data`，失败响应映射为只有一个元素的`errors`，
+/// 响应的扩展属性映射为`meta`。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::{ApiResp, JsonApiResp};
+/// use serde_json::json;
+///
+/// let resp: JsonApiResp = ApiResp::success(json!({"id": "1"})).into();
+/// assert_eq!(resp.to_json(), r#"{"data":{"id":"1"}}"#);
+///
+/// let resp: JsonApiResp = ApiResp::fail(404, "未找到目标记录").into();
+/// assert_eq!(resp.to_json(), r#"{"errors":[{"code":"404","detail":"未找到目标记录"}]}"#);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonApiResp {
+    /// 主数据，成功响应总是输出，没有业务数据时为`null`；外层为`None`表示不输出该属性。
+    #[serde(default, deserialize_with = "crate::serde_ext::deserialize_present", skip_serializing_if = "Option::is_none")]
+    data: Option<Option<serde_json::Value>>,
+    /// 错误列表，仅失败响应输出。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    errors: Option<Vec<JsonApiError>>,
+    /// 扩展信息。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl JsonApiResp {
+    /// 获取主数据，没有主数据或主数据为`null`时返回`None`。
+    pub fn get_data(&self) -> Option<&serde_json::Value> { self.data.as_ref()?.as_ref() }
+
+    pub fn get_errors(&self) -> Option<&[JsonApiError]> { self.errors.as_deref() }
+
+    pub fn get_meta(&self) -> Option<&serde_json::Map<String, serde_json::Value>> { self.meta.as_ref() }
+
+    /// 序列化为JSON字符串，输出时使用[`JSON_API_CONTENT_TYPE`]作为`Content-Type`。
+    pub fn to_json(&self) -> String {
+        // 各属性均为JSON值或字符串，序列化不会失败。
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+impl From<ApiResp> for JsonApiResp {
+    fn from(mut resp: ApiResp) -> Self {
+        let meta = resp.meta.take();
+        if resp.success {
+            JsonApiResp {
+                data: Some(resp.take_data()),
+                errors: None,
+                meta,
+            }
+        } else {
+            JsonApiResp {
+                data: None,
+                errors: Some(vec![JsonApiError { code: resp.code.to_string(), detail: resp.message }]),
+                meta,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_json_api_resp() {
+        let resp: JsonApiResp = ApiResp::suc().into();
+        assert_eq!(resp.to_json(), r#"{"data":null}"#);
+        let back: JsonApiResp = serde_json::from_str(resp.to_json().as_str()).unwrap();
+        assert_eq!(back, resp);
+        assert_eq!(back.to_json(), r#"{"data":null}"#);
+
+        let resp: JsonApiResp = ApiResp::fail(-1, "交易出错了").with_meta("request_id", json!("r-1")).into();
+        assert_eq!(resp.get_data(), None);
+        assert_eq!(resp.get_errors().unwrap()[0].code, "-1");
+        assert_eq!(resp.get_meta().unwrap()["request_id"], "r-1");
+        let back: JsonApiResp = serde_json::from_str(resp.to_json().as_str()).unwrap();
+        assert_eq!(back, resp);
+    }
+}
@@ -1,95 +1,573 @@
 //! 该模块定义通用的异步/远程接口调用结果。
+//!
+//! 默认启用`std`特性。关闭后以`#![no_std]`方式编译，仅依赖`alloc`，
+//! 此时不提供依赖`std::error::Error`的`DaoResult`与`TransformResult`。
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display};
+#[cfg(feature = "std")]
+use core::future::Future;
+use core::sync::atomic::{AtomicI32, Ordering};
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt::{Debug, Display};
-use log::error;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
 use serde::{Serialize,Deserialize};
+use serde::de::DeserializeOwned;
+
+#[cfg(feature = "anyhow")]
+mod anyhow;
+mod api_error;
+mod batch;
+#[cfg(feature = "base64")]
+mod binary;
+mod borrowed;
+mod builder;
+mod category;
+mod details;
+mod error_code;
+#[cfg(feature = "flate2")]
+mod gzip;
+#[cfg(feature = "std")]
+mod hook;
+#[cfg(feature = "http")]
+mod http;
+mod i18n;
+mod json_api;
+mod message;
+mod minimal;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+#[cfg(feature = "std")]
+mod ndjson;
+mod page;
+pub mod prelude;
+#[cfg(feature = "schemars")]
+mod schema;
+mod serde_ext;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+mod status;
+#[cfg(any(test, feature = "test-util"))]
+mod test_util;
+#[cfg(feature = "signing")]
+mod signing;
+mod sse;
+#[cfg(feature = "std")]
+mod transformer;
+#[cfg(feature = "prost")]
+mod proto;
+#[cfg(feature = "raw_value")]
+mod raw;
+mod typed;
+mod validation;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "actix")]
+mod actix;
+#[cfg(feature = "warp")]
+mod warp;
+#[cfg(feature = "tonic")]
+pub mod tonic;
+#[cfg(feature = "rocket")]
+mod rocket;
+#[cfg(feature = "tower")]
+pub mod tower;
+
+#[doc(hidden)]
+pub use log as __log;
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+pub use tracing as __tracing;
+#[doc(hidden)]
+pub use serde_json as __serde_json;
+#[doc(hidden)]
+pub use alloc::format as __format;
+#[doc(hidden)]
+pub use alloc::string::String as __String;
+
+/// 记录错误日志，启用`tracing`特性时使用`tracing::error!`，否则使用`log::error!`。
+///
+/// 以`resp = 响应对象;`或`code = 代码, success = 是否成功;`开头时，`tracing`事件附带`code`与`success`属性。
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_error {
+    (resp = $resp: expr; $($arg: tt)+) => {
+        $crate::__tracing::error!(code = $resp.get_code(), success = $resp.is_success(), $($arg)+)
+    };
+    (code = $code: expr, success = $success: expr; $($arg: tt)+) => {
+        $crate::__tracing::error!(code = $code, success = $success, $($arg)+)
+    };
+    ($($arg: tt)+) => {
+        $crate::__tracing::error!($($arg)+)
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_error {
+    (resp = $resp: expr; $($arg: tt)+) => {
+        $crate::__log::error!($($arg)+)
+    };
+    (code = $code: expr, success = $success: expr; $($arg: tt)+) => {{
+        let _ = ($code, $success);
+        $crate::__log::error!($($arg)+)
+    }};
+    ($($arg: tt)+) => {
+        $crate::__log::error!($($arg)+)
+    };
+}
+
+/// 记录警告日志，启用`tracing`特性时使用`tracing::warn!`，否则使用`log::warn!`。
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_warn {
+    ($($arg: tt)+) => {
+        $crate::__tracing::warn!($($arg)+)
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log_warn {
+    ($($arg: tt)+) => {
+        $crate::__log::warn!($($arg)+)
+    };
+}
+
+pub use api_error::ApiError;
+pub use batch::{BatchApiResp, ItemResult};
+pub use borrowed::ApiRespRef;
+pub use builder::ApiRespBuilder;
+pub use category::{CodeCategory, CodeRanges};
+pub use details::ErrorDetail;
+pub use error_code::ErrorCode;
+#[cfg(feature = "std")]
+pub use hook::{set_response_hook, ResponseHook};
+pub use i18n::MessageResolver;
+pub use json_api::{JsonApiError, JsonApiResp, JSON_API_CONTENT_TYPE};
+pub use message::{Message, ERROR_LEVEL};
+#[cfg(feature = "std")]
+pub use ndjson::write_ndjson;
+pub use page::{CursorPage, PageData};
+#[cfg(feature = "prost")]
+pub use proto::ApiRespProto;
+pub use status::{CodeStatusMap, CodeStatusMapBuilder};
+#[cfg(feature = "std")]
+pub use transformer::{register_transformer, Transformer, TransformerRegistry};
+pub use typed::TypedApiResp;
+pub use validation::FieldErrors;
+
+/// 通用的错误代码，用于没有明确错误代码的失败响应，默认为`-1`。
+static DEFAULT_ERROR_CODE: AtomicI32 = AtomicI32::new(-1);
+
+/// 设置通用的错误代码。
+///
+/// 序列化失败时的响应以及由`TransformResult::to_json_str`等从错误转换而来的响应，都使用该代码。
+///
+/// # Arguments
+///
+/// * `code`: 错误代码。
+///
+/// # Examples
+///
+/// ```
+/// api_resp::set_default_error_code(500);
+/// assert_eq!(api_resp::default_error_code(), 500);
+/// ```
+pub fn set_default_error_code(code: i32) {
+    DEFAULT_ERROR_CODE.store(code, Ordering::Relaxed);
+}
+
+/// 获取通用的错误代码。
+pub fn default_error_code() -> i32 {
+    DEFAULT_ERROR_CODE.load(Ordering::Relaxed)
+}
+
+/// 成功响应的代码，默认为`0`。
+static DEFAULT_SUCCESS_CODE: AtomicI32 = AtomicI32::new(0);
+
+/// 设置成功响应的代码，例如与HTTP约定保持一致时设置为`200`。
+///
+/// `success`、`suc`、`success_msg`等成功响应的构造方法都使用该代码。
+///
+/// # Arguments
+///
+/// * `code`: 成功响应的代码。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::ApiResp;
+/// api_resp::set_default_success_code(200);
+/// assert_eq!(ApiResp::suc().get_code(), 200);
+/// ```
+pub fn set_default_success_code(code: i32) {
+    DEFAULT_SUCCESS_CODE.store(code, Ordering::Relaxed);
+}
+
+/// 获取成功响应的代码。
+pub fn default_success_code() -> i32 {
+    DEFAULT_SUCCESS_CODE.load(Ordering::Relaxed)
+}
+
+/// 序列化为JSON字符串，序列化失败时记录日志并改为输出通用的失败响应。
+///
+/// `code`与`success`为无法序列化的响应的属性，启用`tracing`特性时附带在日志中。
+pub(crate) fn to_json_or_error<S: Serialize + ?Sized>(value: &S, code: Option<i32>, success: Option<bool>) -> String {
+    match serde_json::to_string(value) {
+        Ok(json) => json,
+        Err(e) => {
+            crate::__log_error!(code = code, success = success; "序列化json字符串时出错！{}", e);
+            let err_resp = ApiResp::error(default_error_code(), "处理响应结果时出错！".to_string());
+            serde_json::to_string(&err_resp).unwrap()
+        }
+    }
+}
 
 /// API接口响应数据结构。
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// 启用`camel-case`特性后，属性名按驼峰形式序列化，其中`success`映射为`isSuccess`，
+/// `code`、`message`、`data`保持不变。
+///
+/// 启用`success-as-int`特性后，`success`序列化为`1`或`0`；反序列化时布尔值与`0`/`1`均可接受。
+///
+/// `Serialize`为手写实现，新增属性时需同步修改`serde_ext`中的序列化视图。
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
 pub struct ApiResp {
     /// 执行是否成功
+    #[cfg_attr(feature = "camel-case", serde(rename = "isSuccess"))]
+    #[cfg_attr(feature = "success-as-int", serde(serialize_with = "crate::serde_ext::serialize_success"))]
+    #[serde(deserialize_with = "crate::serde_ext::deserialize_success")]
+    #[cfg_attr(all(feature = "schemars", feature = "success-as-int"), schemars(with = "u8"))]
     success: bool,
-    /// 响应代码
+    /// 响应代码，反序列化时同时接受数字与数字字符串。
+    #[serde(deserialize_with = "crate::serde_ext::deserialize_code")]
     code: i32,
     /// 响应附带消息，通常是错误提示信息。
     message: String,
-    /// 响应数据。
+    /// 响应数据。启用`omit-null-data`特性后，没有业务数据时不输出该属性。
+    #[cfg_attr(feature = "omit-null-data", serde(skip_serializing_if = "Option::is_none"))]
     data: Option<serde_json::Value>,
+    /// 服务端生成响应的时间戳，单位为毫秒。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timestamp: Option<i64>,
+    /// 调用链路的跟踪标识，原样回传给调用方用于关联请求。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trace_id: Option<String>,
+    /// 建议调用方重试的等待时间，单位为秒，通常用于限流响应。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retry_after: Option<u32>,
+    /// 扩展属性，统一输出在`meta`对象中，用于附加各业务方自定义的信息。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Map<String, serde_json::Value>>,
+    /// 接口即将下线的提示，例如下线日期或替代接口，调用方可据此提醒开发人员。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deprecated: Option<String>,
+    /// 幂等请求的标识，原样回传调用方提交的幂等键，用于识别重复处理的请求。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    idempotency_key: Option<String>,
+    /// 带有级别的消息列表，用于同时返回提示与错误等多条消息。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    messages: Option<Vec<Message>>,
+    /// 原样输出的预序列化业务数据，序列化时代替`data`写出。
+    #[cfg(feature = "raw_value")]
+    #[serde(skip)]
+    raw_data: Option<raw::RawData>,
 }
 
 impl ApiResp {
     pub fn is_success(&self) -> bool { self.success }
 
+    /// 是否为失败响应，与`is_success`相反。
+    pub fn is_error(&self) -> bool { !self.success }
+
+    /// 响应代码是否为指定的代码。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::error(404, String::from("未找到目标记录"));
+    /// assert!(resp.is_error() && resp.has_code(404));
+    /// ```
+    pub fn has_code(&self, code: i32) -> bool { self.code == code }
+
+    /// 当前为成功响应时原样返回，否则返回`f`构造的备用响应，例如读取缓存的结果。
+    ///
+    /// 回退时当前失败响应的`code`与`message`等信息全部丢弃，需要保留的应在`f`中自行记录。
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: 构造备用响应的函数，只在当前为失败响应时调用。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let resp = ApiResp::fail(503, "行情服务不可用")
+    ///     .or_else_resp(|| ApiResp::success(json!({"price": 12.5, "cached": true})));
+    /// assert!(resp.is_success());
+    /// assert_eq!(resp.get_message(), "");
+    /// ```
+    pub fn or_else_resp<F: FnOnce() -> ApiResp>(self, f: F) -> ApiResp {
+        if self.success {
+            self
+        } else {
+            f()
+        }
+    }
+
     pub fn get_code(&self) -> i32 { self.code }
 
     pub fn get_message(&self) -> &String { &self.message }
 
+    /// 以字符串切片的形式获取响应附带消息。
+    pub fn message_str(&self) -> &str { self.message.as_str() }
+
+    /// 获取响应附带消息，消息为空时返回指定的默认值。
+    ///
+    /// # Arguments
+    ///
+    /// * `default`: 消息为空时返回的默认值。
+    ///
+    /// returns: &str
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// assert_eq!(ApiResp::error(-1, String::new()).get_message_or("未知错误"), "未知错误");
+    /// assert_eq!(ApiResp::error(-1, String::from("交易出错了")).get_message_or("未知错误"), "交易出错了");
+    /// ```
+    pub fn get_message_or<'a>(&'a self, default: &'a str) -> &'a str {
+        if self.message.is_empty() { default } else { self.message.as_str() }
+    }
+
     pub fn get_data(&self) -> &Option<serde_json::Value> { &self.data }
 
-    pub fn to_json(&self) -> String {
-        match serde_json::to_string(&self) {
-            Ok(json) => json,
-            Err(e) => {
-                error!("序列化json字符串时出错！{}", e);
-                let err_resp = ApiResp::error(-1, "处理响应结果时出错！".to_string());
-                serde_json::to_string(&err_resp).unwrap()
-            }
+    /// 获取业务数据的可变引用，以便在响应生成后补充或修改业务数据，预序列化的业务数据先解析为`data`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let mut resp = ApiResp::success(json!({"id": 1}));
+    /// if let Some(d) = resp.get_data_mut() {
+    ///     d["elapsed"] = json!(12);
+    /// }
+    /// assert_eq!(resp.get_data(), &Some(json!({"id": 1, "elapsed": 12})));
+    /// ```
+    pub fn get_data_mut(&mut self) -> &mut Option<serde_json::Value> {
+        self.materialize_data();
+        &mut self.data
+    }
+
+    /// 设置业务数据，替换原有的预序列化业务数据。
+    pub fn set_data(&mut self, data: serde_json::Value) {
+        #[cfg(feature = "raw_value")]
+        {
+            self.raw_data = None;
         }
+        self.data = Some(data);
     }
-}
 
-impl ApiResp {
-    /// 构造一个成功的响应对象。
+    /// 将业务数据转换为指定的类型。
     ///
-    /// # Arguments
+    /// returns: `Result<Option<T>, serde_json::Error>` 没有业务数据时返回`Ok(None)`。
     ///
-    /// * `data`: 业务数据。
+    /// # Examples
     ///
-    /// returns: ApiResp
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let resp = ApiResp::success(json!(vec![1, 1, 3, 5]));
+    /// let data: Vec<i32> = resp.get_data_as().unwrap().unwrap_or_default();
+    /// assert_eq!(data, vec![1, 1, 3, 5]);
+    /// ```
+    pub fn get_data_as<T: DeserializeOwned>(&self) -> Result<Option<T>, serde_json::Error> {
+        #[cfg(feature = "raw_value")]
+        if let Some(raw) = self.get_raw_data() {
+            return serde_json::from_str(raw.get()).map(Some);
+        }
+        match &self.data {
+            Some(d) => T::deserialize(d).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// 消耗响应对象，将业务数据转换为指定的类型。
+    ///
+    /// returns: `Result<Option<T>, serde_json::Error>` 没有业务数据时返回`Ok(None)`。
+    pub fn into_data_as<T: DeserializeOwned>(self) -> Result<Option<T>, serde_json::Error> {
+        #[cfg(feature = "raw_value")]
+        if let Some(raw) = self.get_raw_data() {
+            return serde_json::from_str(raw.get()).map(Some);
+        }
+        match self.data {
+            Some(d) => serde_json::from_value(d).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// 取出业务数据，携带预序列化的业务数据时返回解析后的值。
+    pub(crate) fn take_data(&mut self) -> Option<serde_json::Value> {
+        self.materialize_data();
+        self.data.take()
+    }
+
+    /// 获取业务数据，携带预序列化的业务数据时返回解析后的值。
+    fn data_value(&self) -> Option<Cow<'_, serde_json::Value>> {
+        #[cfg(feature = "raw_value")]
+        if let Some(raw) = self.get_raw_data() {
+            return serde_json::from_str(raw.get()).ok().map(Cow::Owned);
+        }
+        self.data.as_ref().map(Cow::Borrowed)
+    }
+
+    /// 携带预序列化的业务数据时，将其解析为`data`并清除，以便修改业务数据，修改结果在序列化时生效。
+    fn materialize_data(&mut self) {
+        #[cfg(feature = "raw_value")]
+        if let Some(raw) = self.raw_data.take() {
+            self.data = serde_json::from_str(raw.get()).ok();
+        }
+    }
+
+    /// 业务数据为字符串时，返回该字符串。
+    ///
+    /// returns: `Option<Cow<str>>` 没有业务数据或不是字符串时返回`None`；只有含转义字符的预序列化字符串需要复制。
     ///
     /// # Examples
     ///
     /// ```
     /// use api_resp::ApiResp;
     /// use serde_json::json;
-    /// let data = vec![1,1,3,5];
-    /// let resp = ApiResp::success(json!(data));
+    /// let resp = ApiResp::success(json!("ORD-20240101-0001"));
+    /// assert_eq!(resp.get_data_as_str().as_deref(), Some("ORD-20240101-0001"));
+    /// assert_eq!(resp.get_data_as_i64(), None);
     /// ```
-    pub fn success(data: serde_json::Value) -> ApiResp {
-        ApiResp {
-            success: true,
-            code: 0,
-            message: "".to_string(),
-            data: Some(data),
+    pub fn get_data_as_str(&self) -> Option<Cow<'_, str>> {
+        #[cfg(feature = "raw_value")]
+        if let Some(raw) = self.get_raw_data() {
+            return serde_json::from_str(raw.get()).map(Cow::Borrowed)
+                .or_else(|_| serde_json::from_str(raw.get()).map(Cow::Owned))
+                .ok();
         }
+        self.data.as_ref()?.as_str().map(Cow::Borrowed)
     }
 
-    /// 构造一个成功的简单响应对象，不带任何消息。
+    /// 业务数据为可用`i64`表示的整数时，返回该整数。
     ///
-    /// returns: ApiResp 返回成功响应。
+    /// returns: `Option<i64>` 没有业务数据或不是整数时返回`None`。
     ///
     /// # Examples
     ///
     /// ```
     /// use api_resp::ApiResp;
-    /// let resp = ApiResp::suc();
+    /// use serde_json::json;
+    /// let resp = ApiResp::success(json!(42));
+    /// assert_eq!(resp.get_data_as_i64(), Some(42));
+    /// assert_eq!(resp.get_data_as_f64(), Some(42.0));
     /// ```
-    pub fn suc() -> ApiResp {
-        ApiResp {
-            success: true,
-            code: 0,
-            message: "".to_string(),
-            data: None,
-        }
+    pub fn get_data_as_i64(&self) -> Option<i64> {
+        self.data_value()?.as_i64()
+    }
+
+    /// 业务数据为可用`u64`表示的整数时，返回该整数。
+    ///
+    /// returns: `Option<u64>` 没有业务数据、不是整数或为负数时返回`None`。
+    pub fn get_data_as_u64(&self) -> Option<u64> {
+        self.data_value()?.as_u64()
     }
 
+    /// 业务数据为数值时，返回其`f64`形式，整数同样可以取得。
+    ///
+    /// returns: `Option<f64>` 没有业务数据或不是数值时返回`None`。
+    pub fn get_data_as_f64(&self) -> Option<f64> {
+        self.data_value()?.as_f64()
+    }
 
-    /// 构造一个失败的响应对象。
+    /// 业务数据为布尔值时，返回该值。
+    ///
+    /// returns: `Option<bool>` 没有业务数据或不是布尔值时返回`None`。
+    pub fn get_data_as_bool(&self) -> Option<bool> {
+        self.data_value()?.as_bool()
+    }
+
+    /// 逐个转换数组形式的业务数据中的元素，无需先构造完整的`Vec<T>`，便于增量处理大量数据。
+    ///
+    /// returns: `impl Iterator<Item=Result<T, serde_json::Error>>` 业务数据不是数组时，只产生一个错误。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let resp = ApiResp::success(json!([1, 2, "x"]));
+    /// let items: Vec<Result<i32, _>> = resp.stream_data_items().collect();
+    /// assert_eq!(items.len(), 3);
+    /// assert!(items[2].is_err());
+    /// ```
+    pub fn stream_data_items<'a, T: DeserializeOwned + 'a>(&'a self) -> impl Iterator<Item = Result<T, serde_json::Error>> + 'a {
+        // 预序列化的数组只切分出各元素的JSON文本，逐个转换时再解析。
+        #[cfg(feature = "raw_value")]
+        let raw_items: Option<Vec<&'a str>> = self.get_raw_data()
+            .and_then(|raw| serde_json::from_str::<Vec<&serde_json::value::RawValue>>(raw.get()).ok())
+            .map(|items| items.into_iter().map(serde_json::value::RawValue::get).collect());
+        #[cfg(not(feature = "raw_value"))]
+        let raw_items: Option<Vec<&'a str>> = None;
+        let (items, err) = match (&self.data, &raw_items) {
+            (Some(serde_json::Value::Array(items)), _) => (items.as_slice(), None),
+            (_, Some(_)) => (&[][..], None),
+            _ => (&[][..], Some(<serde_json::Error as serde::de::Error>::custom("业务数据不是数组"))),
+        };
+        err.map(Err).into_iter()
+            .chain(items.iter().map(T::deserialize))
+            .chain(raw_items.into_iter().flatten().map(serde_json::from_str))
+    }
+
+    pub fn get_timestamp(&self) -> Option<i64> { self.timestamp }
+
+    /// 为响应打上当前的服务端时间戳，单位为毫秒。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::suc().with_timestamp();
+    /// assert!(resp.get_timestamp().is_some());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn with_timestamp(mut self) -> ApiResp {
+        let millis = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as i64).unwrap_or_default();
+        self.timestamp = Some(millis);
+        self
+    }
+
+    /// 替换响应代码，其余属性保持不变。
+    ///
+    /// 该方法不会修改`success`，成功响应替换为错误代码后仍然是成功响应。
     ///
     /// # Arguments
     ///
-    /// * `code`: 失败代码。根据具体的业务接口约定取值列表。
-    /// * `message`: 失败信息。
+    /// * `code`: 新的响应代码。
     ///
     /// returns: ApiResp
     ///
@@ -97,72 +575,1275 @@ impl ApiResp {
     ///
     /// ```
     /// use api_resp::ApiResp;
-    /// let resp = ApiResp::fail(-1, String::from("查询信息失败，原因:..."));
+    /// let resp = ApiResp::fail(-1, "交易出错了").with_code(500);
+    /// assert!(resp.is_error() && resp.has_code(500));
     /// ```
-    pub fn error(code: i32, message: String) -> ApiResp {
-        ApiResp {
-            success: false,
-            code,
-            message,
-            data: None,
+    pub fn with_code(mut self, code: i32) -> ApiResp {
+        self.code = code;
+        self
+    }
+
+    /// 替换响应附带消息，其余属性保持不变。
+    ///
+    /// # Arguments
+    ///
+    /// * `message`: 新的消息。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::suc().with_message("保存成功");
+    /// assert_eq!(resp.get_message(), "保存成功");
+    /// ```
+    pub fn with_message(mut self, message: impl Into<String>) -> ApiResp {
+        self.message = message.into();
+        self
+    }
+
+    pub fn get_trace_id(&self) -> Option<&str> { self.trace_id.as_deref() }
+
+    /// 为响应附加调用链路的跟踪标识。
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: 跟踪标识。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::suc().with_trace_id("8f14e45f");
+    /// assert_eq!(resp.get_trace_id(), Some("8f14e45f"));
+    /// ```
+    pub fn with_trace_id(mut self, id: impl Into<String>) -> ApiResp {
+        self.trace_id = Some(id.into());
+        self
+    }
+
+    /// 尚未设置跟踪标识时，生成一个v4 UUID作为跟踪标识，需启用`uuid`特性。
+    ///
+    /// 已通过`with_trace_id`设置的跟踪标识保持不变。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::suc().with_generated_trace_id();
+    /// assert_eq!(resp.get_trace_id().unwrap().len(), 36);
+    ///
+    /// let resp = ApiResp::suc().with_trace_id("8f14e45f").with_generated_trace_id();
+    /// assert_eq!(resp.get_trace_id(), Some("8f14e45f"));
+    /// ```
+    #[cfg(feature = "uuid")]
+    pub fn with_generated_trace_id(mut self) -> ApiResp {
+        if self.trace_id.is_none() {
+            self.trace_id = Some(uuid::Uuid::new_v4().to_string());
         }
+        self
     }
-}
 
-/// 简写的接口返回数据结构定义。
-pub type DaoResult = Result<ApiResp, Box<dyn Error>>;
+    pub fn get_retry_after(&self) -> Option<u32> { self.retry_after }
 
-/// 将API调用结果转换为对外数据形式的特性声明。
-pub trait TransformResult {
-    /// 将API结果转换为JSON字符串。
+    /// 为响应附加建议的重试等待时间。
+    ///
+    /// # Arguments
+    ///
+    /// * `secs`: 等待时间，单位为秒。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::error(429, String::from("请求过于频繁")).with_retry_after(30);
+    /// assert_eq!(resp.get_retry_after(), Some(30));
+    /// ```
+    pub fn with_retry_after(mut self, secs: u32) -> ApiResp {
+        self.retry_after = Some(secs);
+        self
+    }
+
+    /// 获取指定的扩展属性。
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: 扩展属性名。
+    ///
+    /// returns: `Option<&serde_json::Value>`
+    pub fn get_meta(&self, key: &str) -> Option<&serde_json::Value> {
+        self.meta.as_ref().and_then(|m| m.get(key))
+    }
+
+    /// 为响应附加扩展属性，输出在`meta`对象中，同名属性会被覆盖。
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: 扩展属性名。
+    /// * `value`: 扩展属性值。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let resp = ApiResp::suc().with_meta("cache_hit", json!(true));
+    /// assert_eq!(resp.get_meta("cache_hit"), Some(&json!(true)));
+    /// assert!(resp.to_json().contains(r#""meta":{"cache_hit":true}"#));
+    /// ```
+    pub fn with_meta(mut self, key: impl Into<String>, value: serde_json::Value) -> ApiResp {
+        self.meta.get_or_insert_with(serde_json::Map::new).insert(key.into(), value);
+        self
+    }
+
+    /// 在扩展属性中以`version`记录服务端的版本号，便于现场排查问题。
+    ///
+    /// `env!`在调用处展开，因此应由服务端传入自身的版本号，例如`env!("CARGO_PKG_VERSION")`，
+    /// 得到的是服务端crate的版本，而不是本库的版本。
+    ///
+    /// # Arguments
+    ///
+    /// * `v`: 版本号。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::suc().with_version(env!("CARGO_PKG_VERSION"));
+    /// assert_eq!(resp.get_meta("version").unwrap(), env!("CARGO_PKG_VERSION"));
+    /// ```
+    pub fn with_version(self, v: &str) -> ApiResp {
+        self.with_meta("version", serde_json::Value::String(v.to_string()))
+    }
+
+    pub fn get_deprecation(&self) -> Option<&str> { self.deprecated.as_deref() }
+
+    /// 标记接口即将下线，响应中附加`deprecated`提示，不关心该属性的调用方可直接忽略。
+    ///
+    /// # Arguments
+    ///
+    /// * `note`: 下线提示，例如下线日期或替代接口。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::suc().with_deprecation("将于2027-01-01下线，请改用/v2/orders");
+    /// assert!(resp.to_json().contains(r#""deprecated":"将于2027-01-01下线，请改用/v2/orders""#));
+    /// ```
+    pub fn with_deprecation(mut self, note: impl Into<String>) -> ApiResp {
+        self.deprecated = Some(note.into());
+        self
+    }
+
+    pub fn get_idempotency_key(&self) -> Option<&str> { self.idempotency_key.as_deref() }
+
+    /// 回传调用方提交的幂等键。
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: 幂等键。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::suc().with_idempotency_key("order-20261014-0001");
+    /// assert_eq!(resp.get_idempotency_key(), Some("order-20261014-0001"));
+    /// ```
+    pub fn with_idempotency_key(mut self, key: impl Into<String>) -> ApiResp {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    /// 获取带有级别的消息列表，没有消息时返回空切片。
+    pub fn get_messages(&self) -> &[Message] { self.messages.as_deref().unwrap_or_default() }
+
+    /// 追加一条带有级别的消息。
+    ///
+    /// 第一条[`ERROR_LEVEL`]级别的消息在`message`为空时同时写入`message`，以兼容只读取`message`的调用方。
+    ///
+    /// # Arguments
+    ///
+    /// * `level`: 消息级别，例如`info`、`warning`、`error`。
+    /// * `text`: 消息内容。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::fail(400, "")
+    ///     .add_message("info", "已保存草稿")
+    ///     .add_message("error", "提交失败，缺少审批人");
+    /// assert_eq!(resp.get_messages().len(), 2);
+    /// assert_eq!(resp.get_message(), "提交失败，缺少审批人");
+    /// ```
+    pub fn add_message(mut self, level: impl Into<String>, text: impl Into<String>) -> ApiResp {
+        let msg = Message { level: level.into(), text: text.into() };
+        let messages = self.messages.get_or_insert_with(Vec::new);
+        if msg.level == ERROR_LEVEL && self.message.is_empty() && !messages.iter().any(|m| m.level == ERROR_LEVEL) {
+            self.message = msg.text.clone();
+        }
+        messages.push(msg);
+        self
+    }
+
+    /// 对成功响应的业务数据进行转换，失败响应或没有业务数据时原样返回。
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: 业务数据的转换函数。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let resp = ApiResp::success(json!({"name": "tom", "password": "123"}))
+    ///     .map_data(|mut d| {
+    ///         d["password"] = json!("***");
+    ///         d
+    ///     });
+    /// assert_eq!(resp.get_data(), &Some(json!({"name": "tom", "password": "***"})));
+    /// ```
+    pub fn map_data<F: FnOnce(serde_json::Value) -> serde_json::Value>(mut self, f: F) -> ApiResp {
+        if self.success {
+            self.materialize_data();
+            self.data = self.data.map(f);
+        }
+        self
+    }
+
+    /// 合并业务数据，用于组合多个来源的结果。失败响应原样返回。
+    ///
+    /// 原有业务数据与`other`都是对象时，按属性浅合并，属性重复时以`other`为准；否则以`other`替换原有业务数据。
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: 待合并的业务数据。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let resp = ApiResp::success(json!({"id": 1, "name": "tom"})).merge_data(json!({"name": "jerry", "age": 18}));
+    /// assert_eq!(resp.get_data(), &Some(json!({"id": 1, "name": "jerry", "age": 18})));
+    /// ```
+    pub fn merge_data(mut self, other: serde_json::Value) -> ApiResp {
+        if !self.success {
+            return self;
+        }
+        self.materialize_data();
+        match (self.data.as_mut(), other) {
+            (Some(serde_json::Value::Object(map)), serde_json::Value::Object(other_map)) => map.extend(other_map),
+            (_, other) => self.data = Some(other),
+        }
+        self
+    }
+
+    /// 将业务数据中指定属性的值替换为`"***"`，避免敏感信息出现在响应或日志中。
+    ///
+    /// 递归处理嵌套的对象以及数组中的对象，数组与标量本身保持不变。
+    ///
+    /// # Arguments
+    ///
+    /// * `keys`: 需要屏蔽的属性名。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let resp = ApiResp::success(json!({"name": "tom", "password": "123"})).redact(&["password"]);
+    /// assert_eq!(resp.get_data(), &Some(json!({"name": "tom", "password": "***"})));
+    /// ```
+    pub fn redact(mut self, keys: &[&str]) -> ApiResp {
+        self.materialize_data();
+        if let Some(d) = self.data.as_mut() {
+            redact_value(d, keys);
+        }
+        self
+    }
+
+    /// 将响应对象序列化为JSON字符串，序列化前调用通过`set_response_hook`注册的钩子（需启用`std`特性）。
+    pub fn to_json(&self) -> String {
+        #[cfg(feature = "std")]
+        hook::on_response(self);
+        self.serialize_json()
+    }
+
+    /// 序列化为JSON字符串，不调用钩子，`to_json`与签名共用同一份输出。
+    fn serialize_json(&self) -> String {
+        to_json_or_error(self, Some(self.code), Some(self.success))
+    }
+
+    /// 将响应对象以JSON格式写入`w`，不产生中间字符串，序列化前调用已注册的响应钩子。
+    ///
+    /// # Arguments
+    ///
+    /// * `w`: 输出目标。
+    ///
+    /// returns: `Result<(), serde_json::Error>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let mut out = Vec::new();
+    /// ApiResp::suc().write_json(&mut out).unwrap();
+    /// assert_eq!(out, ApiResp::suc().to_json().into_bytes());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_json<W: std::io::Write>(&self, w: W) -> serde_json::Result<()> {
+        hook::on_response(self);
+        serde_json::to_writer(w, self)
+    }
+
+    /// 将JSON字符串写入`buf`，`buf`原有的内容被清除，其已分配的空间可被重复使用。
+    ///
+    /// 序列化失败时与`to_json`一样写入失败响应。
+    ///
+    /// # Arguments
+    ///
+    /// * `buf`: 可重复使用的字符串缓冲区。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let mut buf = String::with_capacity(256);
+    /// for code in [1, 2] {
+    ///     ApiResp::fail(code, "交易出错了").to_json_into(&mut buf);
+    ///     assert_eq!(buf, ApiResp::fail(code, "交易出错了").to_json());
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_json_into(&self, buf: &mut String) {
+        let mut bytes = core::mem::take(buf).into_bytes();
+        bytes.clear();
+        if let Err(e) = self.write_json(&mut bytes) {
+            crate::__log_error!(resp = self; "序列化json字符串时出错！{}", e);
+            bytes.clear();
+            let err_resp = ApiResp::error(default_error_code(), "处理响应结果时出错！".to_string());
+            serde_json::to_writer(&mut bytes, &err_resp).unwrap();
+        }
+        *buf = String::from_utf8(bytes).unwrap_or_default();
+    }
+}
+
+impl ApiResp {
+    /// 构造一个成功的响应对象。
+    ///
+    /// # Arguments
+    ///
+    /// * `data`: 业务数据。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let data = vec![1,1,3,5];
+    /// let resp = ApiResp::success(json!(data));
+    /// ```
+    pub fn success(data: serde_json::Value) -> ApiResp {
+        ApiResp {
+            success: true,
+            code: default_success_code(),
+            message: "".to_string(),
+            data: Some(data),
+            ..ApiResp::default()
+        }
+    }
+
+    /// 以已经序列化的JSON字符串作为业务数据，构造一个成功的响应对象。
+    ///
+    /// 适用于转发下游服务返回的JSON数据，字符串只会被解析一次。
+    ///
+    /// # Arguments
+    ///
+    /// * `raw_json`: 业务数据的JSON字符串。
+    ///
+    /// returns: `Result<ApiResp, serde_json::Error>` 字符串不是合法的JSON时返回解析错误。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::success_raw(r#"{"id": 1}"#).unwrap();
+    /// assert!(resp.is_success());
+    /// assert!(ApiResp::success_raw("{id: 1}").is_err());
+    /// ```
+    pub fn success_raw(raw_json: &str) -> Result<ApiResp, serde_json::Error> {
+        serde_json::from_str(raw_json).map(ApiResp::success)
+    }
+
+    /// 构造一个带有提示信息的成功响应对象。
+    ///
+    /// # Arguments
+    ///
+    /// * `message`: 提示信息。
+    /// * `data`: 业务数据，可以为空。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let resp = ApiResp::success_msg("保存成功，更新了3条记录", Some(json!({"rows": 3})));
+    /// assert!(resp.is_success());
+    /// assert_eq!(resp.get_code(), 0);
+    /// ```
+    pub fn success_msg(message: impl Into<String>, data: Option<serde_json::Value>) -> ApiResp {
+        ApiResp {
+            success: true,
+            code: default_success_code(),
+            message: message.into(),
+            data,
+            ..ApiResp::default()
+        }
+    }
+
+    /// 构造一个指定响应代码的成功响应对象。
+    ///
+    /// 按照约定，成功响应的代码通常为`0`，可以使用正数表示需要调用方留意的成功状态，
+    /// 例如`100`表示请求已受理、正在异步处理。该方法不对代码的取值做任何校验。
+    ///
+    /// # Arguments
+    ///
+    /// * `code`: 响应代码。
+    /// * `data`: 业务数据。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let resp = ApiResp::success_coded(100, json!({"task_id": 1}));
+    /// assert!(resp.is_success());
+    /// assert_eq!(resp.get_code(), 100);
+    /// ```
+    pub fn success_coded(code: i32, data: serde_json::Value) -> ApiResp {
+        ApiResp {
+            success: true,
+            code,
+            message: "".to_string(),
+            data: Some(data),
+            ..ApiResp::default()
+        }
+    }
+
+    /// 将任意可序列化的业务数据构造为成功的响应对象，省去`json!()`转换。
+    ///
+    /// # Arguments
+    ///
+    /// * `data`: 业务数据。
+    ///
+    /// returns: ApiResp 业务数据无法序列化时，返回失败响应。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::of(vec![1,1,3,5]);
+    /// assert!(resp.is_success());
+    /// ```
+    pub fn of<T: Serialize>(data: T) -> ApiResp {
+        match serde_json::to_value(data) {
+            Ok(v) => ApiResp::success(v),
+            Err(e) => {
+                crate::__log_error!("序列化json字符串时出错！{}", e);
+                ApiResp::error(default_error_code(), "处理响应结果时出错！".to_string())
+            }
+        }
+    }
+
+    /// 将任意的执行结果转换为响应对象。
+    ///
+    /// 执行成功时，业务数据无法序列化则返回失败响应；执行失败时记录错误日志，并以错误信息构造失败响应。
+    ///
+    /// # Arguments
+    ///
+    /// * `r`: 执行结果。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let r: Result<Vec<i32>, String> = Err("交易出错了".to_string());
+    /// let resp = ApiResp::from_result(r);
+    /// assert!(!resp.is_success());
+    /// assert_eq!(resp.get_message(), "交易出错了");
+    /// ```
+    pub fn from_result<T: Serialize, E: Display>(r: Result<T, E>) -> ApiResp {
+        match r {
+            Ok(t) => ApiResp::of(t),
+            Err(e) => {
+                crate::__log_error!("{}", e);
+                ApiResp::error(default_error_code(), e.to_string())
+            }
+        }
+    }
+
+    /// 构造一个成功的简单响应对象，不带任何消息。
+    ///
+    /// 业务数据为`None`，序列化为`"data":null`，启用`omit-null-data`特性后不输出`data`属性。
+    /// 需要固定输出空对象时使用[`ApiResp::suc_empty`]。
+    ///
+    /// returns: ApiResp 返回成功响应。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::suc();
+    /// ```
+    pub fn suc() -> ApiResp {
+        ApiResp {
+            success: true,
+            code: default_success_code(),
+            message: "".to_string(),
+            data: None,
+            timestamp: None,
+            trace_id: None,
+            retry_after: None,
+            meta: None,
+            deprecated: None,
+            idempotency_key: None,
+            messages: None,
+            #[cfg(feature = "raw_value")]
+            raw_data: None,
+        }
+    }
+
+    /// 构造一个业务数据为空对象的成功响应。
+    ///
+    /// 与[`ApiResp::suc`]不同，序列化结果总是包含`"data":{}`，不受`omit-null-data`特性影响，
+    /// 便于前端统一按对象读取`data`。
+    ///
+    /// returns: ApiResp 返回成功响应。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::suc_empty();
+    /// assert!(resp.to_json().contains(r#""data":{}"#));
+    /// ```
+    pub fn suc_empty() -> ApiResp {
+        ApiResp::success(serde_json::Value::Object(serde_json::Map::new()))
+    }
+
+
+    /// 构造一个失败的响应对象。
+    ///
+    /// # Arguments
+    ///
+    /// * `code`: 失败代码。根据具体的业务接口约定取值列表。
+    /// * `message`: 失败信息。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::fail(-1, String::from("查询信息失败，原因:..."));
+    /// ```
+    pub fn error(code: i32, message: String) -> ApiResp {
+        ApiResp {
+            success: false,
+            code,
+            message,
+            data: None,
+            ..ApiResp::default()
+        }
+    }
+
+    /// 构造一个失败的响应对象，与[`ApiResp::error`]相同，但提示信息可以是任意可转换为`String`的类型。
+    ///
+    /// # Arguments
+    ///
+    /// * `code`: 失败代码。
+    /// * `message`: 失败信息。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::fail(404, "未找到目标记录");
+    /// assert_eq!(resp, ApiResp::error(404, String::from("未找到目标记录")));
+    /// ```
+    pub fn fail(code: i32, message: impl Into<String>) -> ApiResp {
+        ApiResp::error(code, message.into())
+    }
+
+    /// 由四个基本属性构造响应对象，与[`ApiResp::into_parts`]相对应，其余可选属性均为空。
+    ///
+    /// # Arguments
+    ///
+    /// * `success`: 执行是否成功。
+    /// * `code`: 响应代码。
+    /// * `message`: 响应附带消息。
+    /// * `data`: 业务数据。
+    ///
+    /// returns: ApiResp
+    pub fn from_parts(success: bool, code: i32, message: String, data: Option<serde_json::Value>) -> ApiResp {
+        ApiResp {
+            success,
+            code,
+            message,
+            data,
+            ..ApiResp::default()
+        }
+    }
+
+    /// 仅由响应代码构造响应对象，代码等于[`default_success_code`]时为成功响应，否则为失败响应，
+    /// 适用于对接只返回数字代码的外部系统，无需调用方自行保持`success`与`code`一致。
+    ///
+    /// # Arguments
+    ///
+    /// * `code`: 响应代码。
+    /// * `message`: 响应附带消息。
+    /// * `data`: 业务数据。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::{default_success_code, ApiResp};
+    /// let resp = ApiResp::from_code(default_success_code(), "", None);
+    /// assert!(resp.is_success());
+    /// let resp = ApiResp::from_code(1001, "余额不足", None);
+    /// assert!(resp.is_error());
+    /// ```
+    pub fn from_code(code: i32, message: impl Into<String>, data: Option<serde_json::Value>) -> ApiResp {
+        ApiResp::from_parts(code == default_success_code(), code, message.into(), data)
+    }
+
+    /// 消耗响应对象，按`(success, code, message, data)`的顺序返回四个基本属性，其余可选属性被丢弃。
+    ///
+    /// returns: `(bool, i32, String, Option<serde_json::Value>)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// match ApiResp::fail(404, "未找到目标记录").into_parts() {
+    ///     (true, _, _, data) => println!("{:?}", data),
+    ///     (false, code, message, _) => assert_eq!((code, message.as_str()), (404, "未找到目标记录")),
+    /// }
+    /// let (success, code, message, data) = ApiResp::suc().into_parts();
+    /// assert_eq!(ApiResp::from_parts(success, code, message, data), ApiResp::suc());
+    /// ```
+    pub fn into_parts(mut self) -> (bool, i32, String, Option<serde_json::Value>) {
+        let data = self.take_data();
+        (self.success, self.code, self.message, data)
+    }
+
+    /// 构造一个写操作的成功响应，业务数据为影响的记录数。
+    ///
+    /// 序列化结构示例：
+    /// ```json
+    /// {"rows_affected": 3}
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: 影响的记录数，通常取自数据库执行结果的`rows_affected()`。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::affected(3);
+    /// assert_eq!(resp.get_rows_affected(), Some(3));
+    /// ```
+    pub fn affected(rows: u64) -> ApiResp {
+        let mut data = serde_json::Map::new();
+        data.insert("rows_affected".to_string(), serde_json::Value::from(rows));
+        ApiResp::success(serde_json::Value::Object(data))
+    }
+
+    /// 获取`affected`构造的影响记录数。
+    ///
+    /// returns: `Option<u64>` 业务数据中没有`rows_affected`时返回`None`。
+    pub fn get_rows_affected(&self) -> Option<u64> {
+        self.data_value()?.get("rows_affected")?.as_u64()
+    }
+
+    /// 构造一个带有警告信息的成功响应，用于整体成功但存在部分问题的操作，警告信息保存在扩展属性的`warnings`中。
+    ///
+    /// # Arguments
+    ///
+    /// * `data`: 业务数据。
+    /// * `warnings`: 警告信息列表。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let resp = ApiResp::success_with_warnings(json!({"imported": 10}), vec!["第3行格式有误，已跳过".to_string()]);
+    /// assert!(resp.is_success());
+    /// assert_eq!(resp.get_warnings(), vec!["第3行格式有误，已跳过"]);
+    /// ```
+    pub fn success_with_warnings(data: serde_json::Value, warnings: Vec<String>) -> ApiResp {
+        let warnings = warnings.into_iter().map(serde_json::Value::String).collect();
+        ApiResp::success(data).with_meta("warnings", serde_json::Value::Array(warnings))
+    }
+
+    /// 获取`success_with_warnings`附加的警告信息。
+    ///
+    /// returns: `Vec<String>` 没有警告信息时返回空列表。
+    pub fn get_warnings(&self) -> Vec<String> {
+        self.get_meta("warnings")
+            .and_then(|w| w.as_array())
+            .map(|w| w.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
+            .unwrap_or_default()
+    }
+}
+
+fn redact_value(value: &mut serde_json::Value, keys: &[&str]) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (k, v) in map.iter_mut() {
+                if keys.contains(&k.as_str()) {
+                    *v = serde_json::Value::String("***".to_string());
+                } else {
+                    redact_value(v, keys);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for v in items.iter_mut() {
+                redact_value(v, keys);
+            }
+        }
+        _ => {}
+    }
+}
+
+impl ApiResp {
+    /// 从JSON字符串解析响应对象，与`to_json`相对应。
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: JSON字符串。
+    ///
+    /// returns: `Result<ApiResp, serde_json::Error>` 字符串不是合法的响应结构时返回解析错误。
+    ///
+    /// 嵌套对象、数组以及`i64`/`u64`范围内的整数都能无损还原；浮点数默认按`serde_json`的快速算法解析，
+    /// 个别数值可能与序列化前相差一个最小精度单位，需要精确还原时可启用`float_roundtrip`特性，
+    /// 或启用`arbitrary_precision`特性以原始文本保存所有数值。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::from_json(ApiResp::suc().to_json().as_str()).unwrap();
+    /// assert!(resp.is_success());
+    /// ```
+    pub fn from_json(s: &str) -> Result<ApiResp, serde_json::Error> {
+        serde_json::from_str(s)
+    }
+
+    /// 从JSON字节数组解析响应对象。
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes`: JSON字节数组。
+    ///
+    /// returns: `Result<ApiResp, serde_json::Error>` 数据不是合法的响应结构时返回解析错误。
+    pub fn from_slice(bytes: &[u8]) -> Result<ApiResp, serde_json::Error> {
+        serde_json::from_slice(bytes)
+    }
+}
+
+/// 默认为成功的空响应，与[`ApiResp::suc`]相同。
+impl Default for ApiResp {
+    fn default() -> Self {
+        ApiResp::suc()
+    }
+}
+
+/// 输出简要的响应信息，不包含业务数据的内容，适合用于日志记录。
+impl Display for ApiResp {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        #[cfg(feature = "raw_value")]
+        let has_data = self.data.is_some() || self.raw_data.is_some();
+        #[cfg(not(feature = "raw_value"))]
+        let has_data = self.data.is_some();
+        write!(f, "ApiResp{{success={}, code={}, message={:?}, hasData={}}}",
+               self.success, self.code, self.message, has_data)
+    }
+}
+
+/// 简写的接口返回数据结构定义。
+#[cfg(feature = "std")]
+pub type DaoResult = Result<ApiResp, Box<dyn Error>>;
+
+#[cfg(feature = "std")]
+impl ApiResp {
+    /// 将响应对象包装为`DaoResult`，无论成功与否都返回`Ok`。
+    ///
+    /// returns: DaoResult
+    pub fn into_result(self) -> DaoResult {
+        Ok(self)
+    }
+
+    /// 将响应对象转换为`DaoResult`，失败响应转换为携带提示信息的`Err`，以便使用`?`向上传递。
+    ///
+    /// returns: DaoResult
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::{ApiResp, DaoResult};
+    /// fn check() -> DaoResult {
+    ///     ApiResp::error(-1, String::from("交易出错了")).into_result_err()?;
+    ///     Ok(ApiResp::suc())
+    /// }
+    /// assert_eq!(check().unwrap_err().to_string(), "交易出错了");
+    /// ```
+    pub fn into_result_err(self) -> DaoResult {
+        if self.success {
+            Ok(self)
+        } else {
+            Err(self.message.into())
+        }
+    }
+
+    /// 根据捕获的错误构造一个失败的响应对象，提示信息取自`e`，并在日志中记录完整的错误原因链。
+    ///
+    /// 响应结构保持不变，错误原因只出现在日志中。
+    ///
+    /// # Arguments
+    ///
+    /// * `code`: 失败代码。
+    /// * `e`: 捕获的错误。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let e = std::io::Error::other("连接数据库失败");
+    /// let resp = ApiResp::error_with_source(500, &e);
+    /// assert_eq!(resp.get_message(), "连接数据库失败");
+    /// ```
+    pub fn error_with_source(code: i32, e: &(dyn Error + 'static)) -> ApiResp {
+        let mut chain = e.to_string();
+        let mut source = e.source();
+        while let Some(s) = source {
+            chain.push_str(": ");
+            chain.push_str(s.to_string().as_str());
+            source = s.source();
+        }
+        crate::__log_error!("{}", chain);
+        ApiResp::error(code, e.to_string())
+    }
+}
+
+/// 将JSON处理错误转换为失败响应，以便在返回`Result<_, ApiResp>`的函数中使用`?`。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::ApiResp;
+/// use serde_json::json;
+///
+/// fn parse(v: serde_json::Value) -> Result<Vec<i32>, ApiResp> {
+///     let ids: Vec<i32> = serde_json::from_value(v)?;
+///     Ok(ids)
+/// }
+///
+/// let resp = parse(json!("1,2")).unwrap_err();
+/// assert!(!resp.is_success());
+/// ```
+#[cfg(feature = "std")]
+impl From<serde_json::Error> for ApiResp {
+    fn from(e: serde_json::Error) -> Self {
+        crate::__log_error!("{:?}", e);
+        ApiResp::error(default_error_code(), e.to_string())
+    }
+}
+
+/// 将IO错误转换为失败响应，以便在返回`Result<_, ApiResp>`的函数中使用`?`。
+#[cfg(feature = "std")]
+impl From<std::io::Error> for ApiResp {
+    fn from(e: std::io::Error) -> Self {
+        crate::__log_error!("{:?}", e);
+        ApiResp::error(default_error_code(), e.to_string())
+    }
+}
+
+/// 将API调用结果转换为对外数据形式的特性声明。
+#[cfg(feature = "std")]
+pub trait TransformResult {
+    /// 将API结果转换为JSON字符串。
+    ///
+    /// 为`Result<ApiResp, E>`提供的实现与`ApiResp::to_json`的输出一致，同样会调用已注册的响应钩子，
+    /// 序列化失败时返回通用的失败响应；需要感知序列化错误的应使用`try_to_json_str`。
+    ///
+    /// # Arguments
+    ///
+    /// * `err_log`: 客制化的出错日志信息。
+    ///
+    /// returns: String 返回JSON字符串。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde::{Deserialize, Serialize};
+    /// use std::fmt::{Debug, Display};
+    /// use api_resp::TransformResult;
+    /// #[derive(Debug, Serialize, Deserialize)]
+    /// struct Dept {
+    ///     id: Option<String>,
+    ///     pid: Option<String>,
+    ///     other_attr: Option<i32>
+    /// }
+    ///
+    /// impl TransformResult for Dept {
+    ///     fn to_json_str<T>(self, err_log: T) -> String where T: Debug + Display {
+    ///         serde_json::to_string(&self).unwrap()
+    ///     }
+    /// }
+    ///
+    /// let d = Dept {id: Some("01".to_string()), pid: None, other_attr: Some(10)};
+    ///
+    /// let json_str = d.to_json_str("执行出错".to_string());
+    /// println!("json_str: {}", json_str);
+    /// ```
+    fn to_json_str<T>(self, err_log: T) -> String where T: Debug + Display;
+
+    /// 将API结果转换为JSON字符串，并指定出错日志的级别。
+    ///
+    /// 默认实现忽略日志级别，直接调用`to_json_str`。
+    ///
+    /// # Arguments
+    ///
+    /// * `level`: 出错日志的级别，为`None`时不记录日志，适用于预期内的业务错误。
+    /// * `err_log`: 客制化的出错日志信息。
+    ///
+    /// returns: String 返回JSON字符串。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::{DaoResult, TransformResult};
+    /// let r: DaoResult = Err("参数校验未通过".into());
+    /// let json_str = r.to_json_str_with_level(Some(log::Level::Warn), "校验出错");
+    /// println!("json_str: {}", json_str);
+    /// ```
+    fn to_json_str_with_level<T>(self, level: Option<log::Level>, err_log: T) -> String where T: Debug + Display, Self: Sized {
+        let _ = level;
+        self.to_json_str(err_log)
+    }
+
+    /// 将API结果转换为JSON字符串，序列化失败时返回错误而不是通用的失败响应。
+    ///
+    /// 默认实现直接调用`to_json_str`。
+    ///
+    /// # Arguments
+    ///
+    /// * `err_log`: 客制化的出错日志信息。
+    ///
+    /// returns: `Result<String, serde_json::Error>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::{DaoResult, TransformResult};
+    /// let r: DaoResult = Err("连接数据库失败".into());
+    /// let json_str = r.try_to_json_str("查询出错").unwrap();
+    /// assert!(json_str.contains("连接数据库失败"));
+    /// ```
+    fn try_to_json_str<T>(self, err_log: T) -> Result<String, serde_json::Error> where T: Debug + Display, Self: Sized {
+        Ok(self.to_json_str(err_log))
+    }
+}
+
+/// 适用于任意错误类型的执行结果，包括`DaoResult`以及`Result<ApiResp, sqlx::Error>`等未装箱的错误类型。
+///
+/// 错误类型只要求实现`Debug`与`Display`，因为`Box<dyn Error>`本身并未实现`Error`。
+#[cfg(feature = "std")]
+impl<E: Debug + Display> TransformResult for Result<ApiResp, E> {
+    fn to_json_str<T>(self, err_log: T) -> String where T: Debug + Display {
+        self.to_json_str_with_level(Some(log::Level::Error), err_log)
+    }
+
+    fn to_json_str_with_level<T>(self, level: Option<log::Level>, err_log: T) -> String where T: Debug + Display {
+        into_resp(self, level, err_log).to_json()
+    }
+
+    fn try_to_json_str<T>(self, err_log: T) -> Result<String, serde_json::Error> where T: Debug + Display {
+        let resp = into_resp(self, Some(log::Level::Error), err_log);
+        hook::on_response(&resp);
+        serde_json::to_string(&resp)
+    }
+}
+
+/// 将执行结果转换为响应对象，出错时按指定的级别记录日志。
+///
+/// 启用`tracing`特性时输出相同级别的`tracing`事件，并附带失败响应的`code`与`success`属性。
+#[cfg(feature = "std")]
+fn into_resp<E: Debug + Display, T: Debug + Display>(r: Result<ApiResp, E>, level: Option<log::Level>, err_log: T) -> ApiResp {
+    match r {
+        Ok(r) => r,
+        Err(e) => {
+            let resp = ApiResp::error(default_error_code(), e.to_string());
+            #[cfg(feature = "tracing")]
+            {
+                let (code, success) = (resp.code, resp.success);
+                match level {
+                    Some(log::Level::Error) => tracing::error!(code, success, "{} {:?}", err_log, e),
+                    Some(log::Level::Warn) => tracing::warn!(code, success, "{} {:?}", err_log, e),
+                    Some(log::Level::Info) => tracing::info!(code, success, "{} {:?}", err_log, e),
+                    Some(log::Level::Debug) => tracing::debug!(code, success, "{} {:?}", err_log, e),
+                    Some(log::Level::Trace) => tracing::trace!(code, success, "{} {:?}", err_log, e),
+                    None => {}
+                }
+            }
+            #[cfg(not(feature = "tracing"))]
+            if let Some(level) = level {
+                log::log!(level, "{} {:?}", err_log, e);
+            }
+            resp
+        }
+    }
+}
+
+/// `DaoResult`的组合操作扩展特性。
+#[cfg(feature = "std")]
+pub trait DaoResultExt {
+    /// 当前结果为成功响应时，继续执行下一步操作；出错或失败响应时直接返回当前结果，不再执行`f`。
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: 下一步操作，参数为当前的成功响应。
+    ///
+    /// returns: DaoResult
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::{ApiResp, DaoResult, DaoResultExt};
+    /// use serde_json::json;
+    ///
+    /// fn insert_dept() -> DaoResult { Ok(ApiResp::success(json!({"id": 1}))) }
+    /// fn insert_user(id: i64) -> DaoResult { Ok(ApiResp::error(409, format!("部门{}下已存在该用户", id))) }
+    /// fn grant_roles() -> DaoResult { unreachable!() }
+    ///
+    /// let resp = insert_dept()
+    ///     .and_then_resp(|dept| insert_user(dept.get_data().as_ref().unwrap()["id"].as_i64().unwrap()))
+    ///     .and_then_resp(|_| grant_roles())
+    ///     .unwrap();
+    /// assert_eq!(resp.get_code(), 409);
+    /// ```
+    fn and_then_resp<F: FnOnce(ApiResp) -> DaoResult>(self, f: F) -> DaoResult;
+
+    /// 取出结果中的响应对象，出错时记录日志并以指定的错误代码构造失败响应。
+    ///
+    /// 与`TransformResult::to_json_str`的出错处理一致，区别在于返回响应对象而不是JSON字符串，
+    /// 调用方可在序列化前继续加工，例如设置`trace_id`。
     ///
     /// # Arguments
     ///
-    /// * `err_log`: 客制化的出错日志信息。
+    /// * `code`: 出错时使用的错误代码。
     ///
-    /// returns: String 返回JSON字符串。
+    /// returns: ApiResp
     ///
     /// # Examples
     ///
     /// ```
-    /// use serde::{Deserialize, Serialize};
-    /// use std::fmt::{Debug, Display};
-    /// use api_resp::TransformResult;
-    /// #[derive(Debug, Serialize, Deserialize)]
-    /// struct Dept {
-    ///     id: Option<String>,
-    ///     pid: Option<String>,
-    ///     other_attr: Option<i32>
-    /// }
-    ///
-    /// impl TransformResult for Dept {
-    ///     fn to_json_str<T>(self, err_log: T) -> String where T: Debug + Display {
-    ///         serde_json::to_string(&self).unwrap()
-    ///     }
-    /// }
-    ///
-    /// let d = Dept {id: Some("01".to_string()), pid: None, other_attr: Some(10)};
-    ///
-    /// let json_str = d.to_json_str("执行出错".to_string());
-    /// println!("json_str: {}", json_str);
+    /// use api_resp::{DaoResult, DaoResultExt};
+    /// let r: DaoResult = Err("连接数据库失败".into());
+    /// let resp = r.unwrap_or_error(503).with_trace_id("5f2c9a");
+    /// assert_eq!(resp.get_code(), 503);
+    /// assert_eq!(resp.get_message(), "连接数据库失败");
     /// ```
-    fn to_json_str<T>(self, err_log: T) -> String where T: Debug + Display;
+    fn unwrap_or_error(self, code: i32) -> ApiResp;
 }
 
-impl TransformResult for DaoResult {
-    fn to_json_str<T>(self, err_log: T) -> String where T: Debug + Display {
-        let ret: ApiResp = match self {
+#[cfg(feature = "std")]
+impl DaoResultExt for DaoResult {
+    fn and_then_resp<F: FnOnce(ApiResp) -> DaoResult>(self, f: F) -> DaoResult {
+        match self {
+            Ok(r) if r.success => f(r),
+            other => other,
+        }
+    }
+
+    fn unwrap_or_error(self, code: i32) -> ApiResp {
+        match self {
             Ok(r) => r,
             Err(e) => {
-                error!("{} {:?}", err_log, e);
-                ApiResp::error(-1, e.to_string())
+                crate::__log_error!("{:?}", e);
+                ApiResp::error(code, e.to_string())
             }
-        };
-        serde_json::to_string(&ret).unwrap()
+        }
+    }
+}
+
+/// 将异步执行结果转换为对外数据形式的扩展特性，适用于所有输出为`DaoResult`等执行结果的`Future`。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::{ApiResp, DaoResult, TransformFuture};
+///
+/// async fn query() -> DaoResult {
+///     Ok(ApiResp::suc())
+/// }
+///
+/// async fn handler() -> String {
+///     query().to_json_str_async("查询出错").await
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub trait TransformFuture: Future + Sized where Self::Output: TransformResult {
+    /// 等待异步执行完成，并将结果转换为JSON字符串，等同于`self.await.to_json_str(err_log)`。
+    ///
+    /// # Arguments
+    ///
+    /// * `err_log`: 客制化的出错日志信息。
+    ///
+    /// returns: `impl Future<Output = String>` 返回JSON字符串。
+    fn to_json_str_async<T>(self, err_log: T) -> impl Future<Output = String> where T: Debug + Display {
+        async move { self.await.to_json_str(err_log) }
     }
 }
 
+#[cfg(feature = "std")]
+impl<F: Future> TransformFuture for F where F::Output: TransformResult {}
+
+/// 以格式化的提示信息构造一个失败的响应对象，用法与`format!`相同。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::api_error;
+/// let id = 10;
+/// let resp = api_error!(404, "未找到目标记录: {}", id);
+/// assert_eq!(resp.get_message(), "未找到目标记录: 10");
+/// ```
+#[macro_export]
+macro_rules! api_error {
+    ($code: expr, $($arg: tt)+) => {
+        $crate::ApiResp::error($code, $crate::__format!($($arg)+))
+    };
+}
+
+/// 构造一个成功的响应对象，业务数据的写法与`serde_json::json!`相同，不带参数时构造不带业务数据的成功响应。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::api_success;
+/// let resp = api_success!({"id": 10, "name": "tom"});
+/// assert!(resp.is_success());
+/// let resp = api_success!();
+/// assert!(resp.get_data().is_none());
+/// ```
+#[macro_export]
+macro_rules! api_success {
+    () => {
+        $crate::ApiResp::suc()
+    };
+    ($($data: tt)+) => {
+        $crate::ApiResp::success($crate::__serde_json::json!($($data)+))
+    };
+}
+
+/// 退出当前函数，返回以格式化的提示信息构造的失败响应，适用于返回`DaoResult`的函数。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::{bail_resp, ApiResp, DaoResult};
+/// fn find(id: i32) -> DaoResult {
+///     if id <= 0 {
+///         bail_resp!(400, "无效的编号: {}", id);
+///     }
+///     Ok(ApiResp::suc())
+/// }
+/// assert_eq!(find(-1).unwrap().get_message(), "无效的编号: -1");
+/// ```
+#[macro_export]
+macro_rules! bail_resp {
+    ($code: expr, $($arg: tt)+) => {
+        return Ok($crate::api_error!($code, $($arg)+))
+    };
+}
+
+/// 条件不成立时退出当前函数，返回以格式化的提示信息构造的失败响应，适用于返回`DaoResult`的函数。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::{ensure_resp, ApiResp, DaoResult};
+/// fn rename(name: &str) -> DaoResult {
+///     ensure_resp!(!name.is_empty(), 400, "名称不能为空");
+///     ensure_resp!(name.len() <= 8, 400, "名称长度不能超过{}", 8);
+///     Ok(ApiResp::suc())
+/// }
+/// assert_eq!(rename("").unwrap().get_code(), 400);
+/// assert!(rename("tom").unwrap().is_success());
+/// ```
+#[macro_export]
+macro_rules! ensure_resp {
+    ($cond: expr, $code: expr, $($arg: tt)+) => {
+        if !$cond {
+            $crate::bail_resp!($code, $($arg)+);
+        }
+    };
+}
+
 /// 回滚当前的事务后退出当前函数，返回包含通用错误信息的结果对象。
+///
+/// 适用于异步事务，即`$tx.rollback()`返回`Future`的情况；同步事务请使用[`rollback_sync!`]。
 #[macro_export]
 macro_rules! rollback {
     ($resp: expr, $tx: expr, $code: expr) => {
@@ -173,7 +1854,24 @@ macro_rules! rollback {
     };
 }
 
+/// 回滚当前的事务后退出当前函数，返回包含指定提示信息的结果对象。
+///
+/// 原始错误只记录到错误日志中，不会出现在返回结果里，避免向调用方暴露数据库等内部细节。
+/// 适用于异步事务；同步事务请使用[`rollback_with_msg_sync!`]。
+#[macro_export]
+macro_rules! rollback_with_msg {
+    ($resp: expr, $tx: expr, $code: expr, $msg: expr) => {
+        if let Err(e) = $resp {
+            $crate::__log_error!("{:?}", e);
+            $tx.rollback().await?;
+            return Ok($crate::ApiResp::error($code, $msg.to_string()));
+        }
+    };
+}
+
 /// 当出现错误或更新记录数未0时，回滚当前的事务后退出当前函数，返回包含通用错误信息的结果对象。
+///
+/// 适用于异步事务；同步事务请使用[`rollback_for_no_match_sync!`]。
 #[macro_export]
 macro_rules! rollback_for_no_match {
     ($resp: expr, $tx: expr, $code: expr) => {
@@ -191,11 +1889,93 @@ macro_rules! rollback_for_no_match {
     };
 }
 
+/// [`rollback!`]的同步版本，适用于`$tx.rollback()`直接返回`Result`的同步事务，如`rusqlite`、`diesel`。
+#[macro_export]
+macro_rules! rollback_sync {
+    ($resp: expr, $tx: expr, $code: expr) => {
+        if let Err(e) = $resp {
+            $tx.rollback()?;
+            return Ok($crate::ApiResp::error($code, e.to_string()));
+        }
+    };
+}
+
+/// [`rollback_with_msg!`]的同步版本，适用于`$tx.rollback()`直接返回`Result`的同步事务。
+#[macro_export]
+macro_rules! rollback_with_msg_sync {
+    ($resp: expr, $tx: expr, $code: expr, $msg: expr) => {
+        if let Err(e) = $resp {
+            $crate::__log_error!("{:?}", e);
+            $tx.rollback()?;
+            return Ok($crate::ApiResp::error($code, $msg.to_string()));
+        }
+    };
+}
+
+/// [`rollback_for_no_match!`]的同步版本，适用于`$tx.rollback()`直接返回`Result`的同步事务。
+#[macro_export]
+macro_rules! rollback_for_no_match_sync {
+    ($resp: expr, $tx: expr, $code: expr) => {
+        match $resp {
+            Err(e) => {
+                $tx.rollback()?;
+                return Ok($crate::ApiResp::error($code, e.to_string()));
+            },
+            Ok(r) if r.rows_affected == 0 => {
+                $tx.rollback()?;
+                return Ok($crate::ApiResp::error($code, "未匹配到目标记录".to_string()));
+            },
+            _ => {}
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
     use serde_json::json;
     use super::*;
 
+    /// 模拟的数据库事务。
+    struct MockTx {
+        rolled_back: bool,
+    }
+
+    impl MockTx {
+        async fn rollback(&mut self) -> Result<(), Box<dyn Error>> {
+            self.rolled_back = true;
+            Ok(())
+        }
+    }
+
+    /// 模拟的同步数据库事务。
+    struct MockSyncTx {
+        rolled_back: bool,
+    }
+
+    impl MockSyncTx {
+        fn rollback(&mut self) -> Result<(), Box<dyn Error>> {
+            self.rolled_back = true;
+            Ok(())
+        }
+    }
+
+    /// 模拟的数据库更新结果。
+    struct MockUpdated {
+        rows_affected: u64,
+    }
+
+    fn block_on<F: Future>(f: F) -> F::Output {
+        let mut f = pin!(f);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(r) = f.as_mut().poll(&mut cx) {
+                return r;
+            }
+        }
+    }
+
     #[derive(Debug, Serialize, Deserialize)]
     struct PingPang {
         color: String,
@@ -226,4 +2006,456 @@ mod tests {
         let orig_fail: ApiResp = serde_json::from_str(fail_json.as_str()).unwrap();
         assert!(!orig_fail.is_success());
     }
+
+    #[test]
+    fn test_message_or() {
+        let fail = ApiResp::error(-1, String::from("交易出错了"));
+        assert_eq!(fail.message_str(), "交易出错了");
+        assert_eq!(fail.get_message_or("未知错误"), "交易出错了");
+        assert_eq!(ApiResp::suc().get_message_or("未知错误"), "未知错误");
+    }
+
+    #[test]
+    fn test_clone_eq() {
+        let resp = ApiResp::success(json!({"id": 1}));
+        let orig: ApiResp = serde_json::from_str(&resp.to_json()).unwrap();
+        assert_eq!(orig, resp.clone());
+        assert_ne!(orig, ApiResp::suc());
+    }
+
+    #[test]
+    fn test_rollback_with_msg() {
+        async fn save(tx: &mut MockTx) -> DaoResult {
+            let r: Result<(), String> = Err("duplicate key value violates unique constraint".to_string());
+            rollback_with_msg!(r, tx, 500, "保存失败");
+            Ok(ApiResp::suc())
+        }
+
+        let mut tx = MockTx { rolled_back: false };
+        let resp = block_on(save(&mut tx)).unwrap();
+        assert!(tx.rolled_back);
+        assert_eq!(resp.get_message(), "保存失败");
+    }
+
+    #[test]
+    fn test_rollback_sync() {
+        fn update(tx: &mut MockSyncTx, rows_affected: u64) -> DaoResult {
+            let r: Result<MockUpdated, String> = Ok(MockUpdated { rows_affected });
+            rollback_for_no_match_sync!(r, tx, 404);
+            Ok(ApiResp::suc())
+        }
+
+        let mut tx = MockSyncTx { rolled_back: false };
+        let resp = update(&mut tx, 0).unwrap();
+        assert!(tx.rolled_back);
+        assert_eq!(resp.get_code(), 404);
+
+        let mut tx = MockSyncTx { rolled_back: false };
+        let resp = update(&mut tx, 1).unwrap();
+        assert!(!tx.rolled_back);
+        assert!(resp.is_success());
+    }
+
+    #[test]
+    fn test_transform_result() {
+        #[derive(Debug)]
+        struct DbError;
+
+        impl Display for DbError {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "连接数据库失败")
+            }
+        }
+
+        impl Error for DbError {}
+
+        let boxed: DaoResult = Err(Box::new(DbError));
+        let unboxed: Result<ApiResp, DbError> = Err(DbError);
+        assert_eq!(boxed.to_json_str("查询出错"), unboxed.to_json_str("查询出错"));
+
+        let unboxed: Result<ApiResp, DbError> = Err(DbError);
+        assert_eq!(unboxed.try_to_json_str("查询出错").unwrap(), ApiResp::fail(default_error_code(), "连接数据库失败").to_json());
+        let ok: Result<ApiResp, DbError> = Ok(ApiResp::suc());
+        assert_eq!(ok.try_to_json_str("查询出错").unwrap(), ApiResp::suc().to_json());
+    }
+
+    #[test]
+    fn test_null_data() {
+        let suc_json = ApiResp::suc().to_json();
+        if cfg!(feature = "omit-null-data") {
+            assert!(!suc_json.contains("data"));
+        } else {
+            assert!(suc_json.contains(r#""data":null"#));
+        }
+        let orig_suc: ApiResp = serde_json::from_str(suc_json.as_str()).unwrap();
+        assert_eq!(orig_suc, ApiResp::suc());
+
+        // 两种格式均可被解析。
+        let mut fields = serde_json::to_value(ApiResp::suc()).unwrap();
+        fields.as_object_mut().unwrap().insert("data".to_string(), serde_json::Value::Null);
+        let with_null: ApiResp = serde_json::from_value(fields.clone()).unwrap();
+        fields.as_object_mut().unwrap().remove("data");
+        let without_data: ApiResp = serde_json::from_value(fields).unwrap();
+        assert_eq!(with_null, without_data);
+
+        // 空对象的成功响应总是输出data属性。
+        let empty_json = ApiResp::suc_empty().to_json();
+        assert!(empty_json.contains(r#""data":{}"#));
+        assert_eq!(ApiResp::from_json(empty_json.as_str()).unwrap(), ApiResp::suc_empty());
+        assert_ne!(ApiResp::suc_empty(), ApiResp::suc());
+    }
+
+    #[test]
+    fn test_transform_future() {
+        async fn query() -> DaoResult {
+            Err("连接数据库失败".into())
+        }
+
+        let json_str = block_on(query().to_json_str_async("查询出错"));
+        let resp: ApiResp = serde_json::from_str(json_str.as_str()).unwrap();
+        assert_eq!(resp.get_message(), "连接数据库失败");
+    }
+
+    #[test]
+    fn test_redact() {
+        let resp = ApiResp::success(json!([
+            {"name": "tom", "token": "abc", "profile": {"password": "123", "age": 18}},
+            "password",
+            10,
+        ])).redact(&["password", "token"]);
+        assert_eq!(resp.get_data(), &Some(json!([
+            {"name": "tom", "token": "***", "profile": {"password": "***", "age": 18}},
+            "password",
+            10,
+        ])));
+    }
+
+    #[test]
+    #[cfg(feature = "preserve_order")]
+    fn test_preserve_order() {
+        let data_json = r#"{"zeta":1,"alpha":2,"mu":3}"#;
+        let resp = ApiResp::success_raw(data_json).unwrap();
+        let orig: ApiResp = serde_json::from_str(resp.to_json().as_str()).unwrap();
+        assert_eq!(serde_json::to_string(orig.get_data()).unwrap(), data_json);
+    }
+
+    #[test]
+    fn test_from_json() {
+        let resp = ApiResp::success(json!({"id": 1})).with_trace_id("8f14e45f");
+        assert_eq!(ApiResp::from_json(resp.to_json().as_str()).unwrap(), resp);
+        assert_eq!(ApiResp::from_slice(resp.to_json().as_bytes()).unwrap(), resp);
+
+        // 非法的输入。
+        assert!(ApiResp::from_json("").is_err());
+        assert!(ApiResp::from_json("{success: true}").is_err());
+        assert!(ApiResp::from_json(r#"{"code":0,"message":""}"#).is_err());
+        assert!(ApiResp::from_json(r#"[true, 0, ""]"#).is_err());
+        assert!(ApiResp::from_slice(&[0xff, 0xfe]).is_err());
+    }
+
+    #[test]
+    fn test_nested_round_trip() {
+        let data = json!({
+            "user": {"id": i64::MAX, "name": "张三", "tags": ["a", "b"], "profile": {"deep": {"deeper": [1, [2, [3]]]}}},
+            "min": i64::MIN,
+            "max_u64": u64::MAX,
+            "items": [{"price": 0.1}, {"price": 1e-300}, {"price": -2.5e300}],
+            "empty": {"list": [], "obj": {}},
+        });
+        let resp = ApiResp::success(data.clone());
+        let back = ApiResp::from_json(resp.to_json().as_str()).unwrap();
+        assert_eq!(back.get_data(), &Some(data));
+        let back_data = back.get_data().as_ref().unwrap();
+        assert_eq!(back_data["min"].as_i64(), Some(i64::MIN));
+        assert_eq!(back_data["max_u64"].as_u64(), Some(u64::MAX));
+        assert_eq!(back_data["user"]["id"].as_i64(), Some(i64::MAX));
+
+        // 默认的浮点数解析可能存在一个最小精度单位的误差，启用相应特性后可精确还原。
+        let f = 3.547080311279209e106_f64;
+        let back = ApiResp::from_json(ApiResp::success(json!(f)).to_json().as_str()).unwrap();
+        let back_f = back.get_data().as_ref().unwrap().as_f64().unwrap();
+        if cfg!(any(feature = "float_roundtrip", feature = "arbitrary_precision")) {
+            assert_eq!(back_f, f);
+        } else {
+            assert!(((back_f - f) / f).abs() <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_error_with_source() {
+        #[derive(Debug)]
+        struct QueryError(std::io::Error);
+
+        impl Display for QueryError {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "查询订单失败")
+            }
+        }
+
+        impl Error for QueryError {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let e = QueryError(std::io::Error::other("连接数据库失败"));
+        let resp = ApiResp::error_with_source(500, &e);
+        assert_eq!(resp, ApiResp::error(500, "查询订单失败".to_string()));
+    }
+
+    #[test]
+    fn test_success_repr() {
+        let json = ApiResp::suc().to_json();
+        let key = if cfg!(feature = "camel-case") { "isSuccess" } else { "success" };
+        let expected = if cfg!(feature = "success-as-int") { 1.to_string() } else { true.to_string() };
+        assert!(json.contains(format!(r#""{}":{}"#, key, expected).as_str()));
+        assert_eq!(ApiResp::from_json(json.as_str()).unwrap(), ApiResp::suc());
+
+        // 两种形式均可被解析。
+        for (v, success) in [(json!(true), true), (json!(1), true), (json!(false), false), (json!(0), false)] {
+            let mut fields = serde_json::to_value(ApiResp::suc()).unwrap();
+            fields[key] = v;
+            let resp: ApiResp = serde_json::from_value(fields).unwrap();
+            assert_eq!(resp.is_success(), success);
+        }
+        let mut fields = serde_json::to_value(ApiResp::suc()).unwrap();
+        fields[key] = json!(2);
+        assert!(serde_json::from_value::<ApiResp>(fields).is_err());
+    }
+
+    #[test]
+    fn test_stream_data_items() {
+        let vals = vec![
+            PingPang {color: "white".to_string(), weight: 10.0},
+            PingPang {color: "yellow".to_string(), weight: 11.5},
+        ];
+        let resp = ApiResp::success(json!(vals));
+        let colors: Vec<String> = resp.stream_data_items::<PingPang>().map(|p| p.unwrap().color).collect();
+        assert_eq!(colors, vec!["white", "yellow"]);
+
+        let items: Vec<Result<PingPang, _>> = ApiResp::success(json!({"color": "white"})).stream_data_items().collect();
+        assert_eq!(items.len(), 1);
+        assert!(items[0].is_err());
+        assert_eq!(ApiResp::suc().stream_data_items::<PingPang>().count(), 1);
+    }
+
+    #[test]
+    fn test_write_json() {
+        let resp = ApiResp::success(json!({"id": 1})).with_trace_id("8f14e45f");
+        let mut out = Vec::new();
+        resp.write_json(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), resp.to_json());
+
+        let mut buf = String::from("旧的内容");
+        resp.to_json_into(&mut buf);
+        assert_eq!(buf, resp.to_json());
+        let capacity = buf.capacity();
+        ApiResp::suc().to_json_into(&mut buf);
+        assert_eq!(buf, ApiResp::suc().to_json());
+        assert_eq!(buf.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_scalar_data() {
+        let resp = ApiResp::success(json!(-3));
+        assert_eq!(resp.get_data_as_i64(), Some(-3));
+        assert_eq!(resp.get_data_as_u64(), None);
+        assert_eq!(resp.get_data_as_bool(), None);
+        assert_eq!(ApiResp::success(json!(true)).get_data_as_bool(), Some(true));
+        assert_eq!(ApiResp::success(json!(1.5)).get_data_as_i64(), None);
+        assert_eq!(ApiResp::suc().get_data_as_str(), None);
+        assert_eq!(ApiResp::success(json!("tom")).get_data_as_str().as_deref(), Some("tom"));
+    }
+
+    #[test]
+    fn test_tolerant_deserialize() {
+        let mut fields = serde_json::to_value(ApiResp::fail(404, "未找到目标记录")).unwrap();
+        fields["code"] = json!("404");
+        fields["upstream"] = json!({"host": "10.0.0.1"});
+        let resp: ApiResp = serde_json::from_value(fields.clone()).unwrap();
+        assert_eq!(resp, ApiResp::fail(404, "未找到目标记录"));
+
+        fields["code"] = json!(404);
+        assert_eq!(serde_json::from_value::<ApiResp>(fields.clone()).unwrap(), resp);
+
+        for code in [json!("abc"), json!(1.5), json!(i64::MAX)] {
+            fields["code"] = code;
+            assert!(serde_json::from_value::<ApiResp>(fields.clone()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let resp = ApiResp::success(json!({"id": 1}));
+        assert_eq!(resp.to_string(), r#"ApiResp{success=true, code=0, message="", hasData=true}"#);
+        let resp = ApiResp::error(-1, String::from("交易出错了"));
+        assert_eq!(resp.to_string(), r#"ApiResp{success=false, code=-1, message="交易出错了", hasData=false}"#);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_timestamp() {
+        // 未设置时间戳时，序列化结果保持不变。
+        let suc_json = ApiResp::suc().to_json();
+        assert!(!suc_json.contains("timestamp"));
+
+        let stamped = ApiResp::suc().with_timestamp();
+        let orig_stamped: ApiResp = serde_json::from_str(stamped.to_json().as_str()).unwrap();
+        assert_eq!(orig_stamped.get_timestamp(), stamped.get_timestamp());
+    }
+
+    #[test]
+    fn test_trace_id() {
+        let plain_json = ApiResp::suc().to_json();
+        assert!(!plain_json.contains("trace_id"));
+        let orig_plain: ApiResp = serde_json::from_str(plain_json.as_str()).unwrap();
+        assert_eq!(orig_plain.get_trace_id(), None);
+
+        let traced_json = ApiResp::suc().with_trace_id("8f14e45f").to_json();
+        assert!(traced_json.contains("8f14e45f"));
+        let orig_traced: ApiResp = serde_json::from_str(traced_json.as_str()).unwrap();
+        assert_eq!(orig_traced.get_trace_id(), Some("8f14e45f"));
+    }
+
+    #[test]
+    fn test_with_code() {
+        let orig = ApiResp::success(json!({"id": 1})).with_trace_id("8f14e45f");
+        let resp = orig.clone().with_code(201).with_message("已创建");
+        assert!(resp.is_success());
+        assert_eq!(resp.get_code(), 201);
+        assert_eq!(resp.get_message(), "已创建");
+        assert_eq!(resp.get_data(), orig.get_data());
+        assert_eq!(resp.get_trace_id(), orig.get_trace_id());
+    }
+
+    #[test]
+    fn test_deprecation() {
+        assert!(!ApiResp::suc().to_json().contains("deprecated"));
+        let resp = ApiResp::suc().with_deprecation("将于2027-01-01下线");
+        let orig = ApiResp::from_json(resp.to_json().as_str()).unwrap();
+        assert_eq!(orig.get_deprecation(), Some("将于2027-01-01下线"));
+        assert_eq!(orig, resp);
+    }
+
+    #[test]
+    fn test_idempotency_key() {
+        assert!(!ApiResp::suc().to_json().contains("idempotency"));
+        let resp = ApiResp::suc().with_idempotency_key("order-0001");
+        let orig = ApiResp::from_json(resp.to_json().as_str()).unwrap();
+        assert_eq!(orig.get_idempotency_key(), Some("order-0001"));
+        assert_eq!(orig, resp);
+    }
+
+    #[test]
+    fn test_messages() {
+        assert!(!ApiResp::suc().to_json().contains("messages"));
+        assert!(ApiResp::suc().get_messages().is_empty());
+
+        let resp = ApiResp::suc()
+            .add_message("info", "已保存草稿")
+            .add_message("error", "第一个错误")
+            .add_message("error", "第二个错误");
+        assert_eq!(resp.get_message(), "第一个错误");
+        assert_eq!(resp.get_messages()[2], Message { level: "error".to_string(), text: "第二个错误".to_string() });
+        assert_eq!(ApiResp::from_json(resp.to_json().as_str()).unwrap(), resp);
+
+        // 已有的消息不被替换。
+        let resp = ApiResp::fail(-1, "交易出错了").add_message("error", "余额不足");
+        assert_eq!(resp.get_message(), "交易出错了");
+    }
+
+    #[test]
+    fn test_meta() {
+        let plain_json = ApiResp::suc().to_json();
+        assert!(!plain_json.contains("meta"));
+        assert_eq!(ApiResp::from_json(plain_json.as_str()).unwrap().get_meta("cache_hit"), None);
+
+        let resp = ApiResp::suc().with_meta("server_version", json!("1.2.0")).with_meta("cache_hit", json!(false));
+        let orig = ApiResp::from_json(resp.to_json().as_str()).unwrap();
+        assert_eq!(orig, resp);
+        assert_eq!(orig.get_meta("server_version"), Some(&json!("1.2.0")));
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let orig: ApiResp = serde_json::from_str(ApiResp::error(429, String::from("请求过于频繁")).to_json().as_str()).unwrap();
+        assert_eq!(orig.get_retry_after(), None);
+
+        let limited = ApiResp::error(429, String::from("请求过于频繁")).with_retry_after(30);
+        let orig_limited: ApiResp = serde_json::from_str(limited.to_json().as_str()).unwrap();
+        assert_eq!(orig_limited, limited);
+    }
+
+    #[test]
+    fn test_typed_resp() {
+        let vals = vec![
+            PingPang {color: "white".to_string(), weight: 10.0},
+            PingPang {color: "yellow".to_string(), weight: 11.5},
+        ];
+        let typed_json = TypedApiResp::success(vals).to_json();
+        println!("typed_json: {}", typed_json);
+
+        // 与不带类型参数的响应结构在序列化格式上保持一致。
+        let orig_untyped: ApiResp = serde_json::from_str(typed_json.as_str()).unwrap();
+        assert!(orig_untyped.is_success());
+        let orig_typed: TypedApiResp<Vec<PingPang>> = serde_json::from_str(typed_json.as_str()).unwrap();
+        assert_eq!(orig_typed.get_data().as_ref().unwrap().len(), 2);
+
+        let untyped = orig_typed.into_untyped();
+        assert_eq!(untyped.to_json(), typed_json);
+    }
+
+    #[test]
+    #[cfg(feature = "camel-case")]
+    fn test_camel_case() {
+        let suc_json = ApiResp::success(json!(1)).to_json();
+        let success = if cfg!(feature = "success-as-int") { "1" } else { "true" };
+        assert_eq!(suc_json, format!(r#"{{"isSuccess":{},"code":0,"message":"","data":1}}"#, success));
+        let orig_suc: ApiResp = serde_json::from_str(suc_json.as_str()).unwrap();
+        assert!(orig_suc.is_success());
+        assert_eq!(orig_suc.to_json(), suc_json);
+    }
+
+    /// 记录`tracing`事件的级别与属性名的订阅器。
+    #[cfg(feature = "tracing")]
+    #[derive(Default)]
+    struct EventRecorder(std::sync::Mutex<Vec<(tracing::Level, Vec<&'static str>)>>);
+
+    #[cfg(feature = "tracing")]
+    impl tracing::Subscriber for EventRecorder {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool { true }
+
+        fn new_span(&self, _: &tracing::span::Attributes<'_>) -> tracing::span::Id { tracing::span::Id::from_u64(1) }
+
+        fn record(&self, _: &tracing::span::Id, _: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            let fields = event.metadata().fields().iter().map(|f| f.name()).collect();
+            self.0.lock().unwrap().push((*event.metadata().level(), fields));
+        }
+
+        fn enter(&self, _: &tracing::span::Id) {}
+
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_transform_result_tracing() {
+        let recorder = std::sync::Arc::new(EventRecorder::default());
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let r: DaoResult = Err("连接数据库失败".into());
+            r.to_json_str("查询出错");
+            let r: DaoResult = Err("参数校验未通过".into());
+            r.to_json_str_with_level(Some(log::Level::Warn), "校验出错");
+            let r: DaoResult = Err("记录已存在".into());
+            r.to_json_str_with_level(None, "保存出错");
+        });
+        let events = recorder.0.lock().unwrap();
+        let levels: Vec<_> = events.iter().map(|(level, _)| *level).collect();
+        assert_eq!(levels, vec![tracing::Level::ERROR, tracing::Level::WARN]);
+        assert!(events.iter().all(|(_, fields)| fields.contains(&"code") && fields.contains(&"success")));
+    }
 }
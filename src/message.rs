@@ -0,0 +1,16 @@
+//! 带有级别的响应消息。
+use alloc::string::String;
+use serde::{Serialize, Deserialize};
+
+/// 带有级别的响应消息，例如`info`、`warning`、`error`。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Message {
+    /// 消息级别。
+    pub level: String,
+    /// 消息内容。
+    pub text: String,
+}
+
+/// 错误级别的名称，该级别的第一条消息同时作为响应的`message`。
+pub const ERROR_LEVEL: &str = "error";
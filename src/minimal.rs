@@ -0,0 +1,121 @@
+//! 响应对象的精简输出形式，用于健康检查等高频接口以及审计日志。
+use alloc::string::String;
+use serde::Serialize;
+
+use crate::ApiResp;
+
+/// 只包含`success`的响应结构。
+#[derive(Serialize)]
+struct MinimalView {
+    #[cfg_attr(feature = "camel-case", serde(rename = "isSuccess"))]
+    #[cfg_attr(feature = "success-as-int", serde(serialize_with = "crate::serde_ext::serialize_success"))]
+    success: bool,
+}
+
+/// 只包含`success`、`code`、`message`的响应结构。
+#[derive(Serialize)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+struct HeadersView<'a> {
+    #[cfg_attr(feature = "camel-case", serde(rename = "isSuccess"))]
+    #[cfg_attr(feature = "success-as-int", serde(serialize_with = "crate::serde_ext::serialize_success"))]
+    success: bool,
+    code: i32,
+    message: &'a str,
+}
+
+impl ApiResp {
+    /// 构造一个可以按最精简形式输出的成功响应，与[`ApiResp::suc`]相同。
+    ///
+    /// 需配合[`ApiResp::to_json_minimal`]使用，`to_json`的输出保持完整的结构。
+    ///
+    /// returns: ApiResp
+    pub fn minimal_suc() -> ApiResp {
+        ApiResp::suc()
+    }
+
+    /// 序列化为JSON字符串，与[`ApiResp::suc`]相同的响应，即没有消息、业务数据及其他可选属性，
+    /// 且代码为默认成功代码的成功响应，只输出`{"success":true}`；其余响应与`to_json`的输出相同。
+    ///
+    /// 精简形式缺少`code`等属性，仅适用于调用方明确接受该形式的接口。
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// // 输出 {"success":true}
+    /// let json = ApiResp::minimal_suc().to_json_minimal();
+    /// assert!(!json.contains("code") && !json.contains("data"));
+    /// assert_eq!(ApiResp::fail(-1, "交易出错了").to_json_minimal(), ApiResp::fail(-1, "交易出错了").to_json());
+    /// ```
+    pub fn to_json_minimal(&self) -> String {
+        if *self == ApiResp::suc() {
+            #[cfg(feature = "std")]
+            crate::hook::on_response(self);
+            serde_json::to_string(&MinimalView { success: true }).unwrap_or_default()
+        } else {
+            self.to_json()
+        }
+    }
+
+    /// 只将`success`、`code`、`message`序列化为JSON字符串，不包含业务数据，适合逐条记录到审计日志中。
+    ///
+    /// 与`Display`的输出不同，结果是合法的JSON。
+    ///
+    /// returns: String
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let resp = ApiResp::success(json!({"password": "123"}));
+    /// let headers: serde_json::Value = serde_json::from_str(&resp.to_json_headers_only()).unwrap();
+    /// assert_eq!(headers["code"], 0);
+    /// assert!(headers.get("data").is_none());
+    /// ```
+    pub fn to_json_headers_only(&self) -> String {
+        let view = HeadersView {
+            success: self.success,
+            code: self.code,
+            message: self.message.as_str(),
+        };
+        serde_json::to_string(&view).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_to_json_minimal() {
+        let expected = match (cfg!(feature = "camel-case"), cfg!(feature = "success-as-int")) {
+            (false, false) => r#"{"success":true}"#,
+            (false, true) => r#"{"success":1}"#,
+            (true, false) => r#"{"isSuccess":true}"#,
+            (true, true) => r#"{"isSuccess":1}"#,
+        };
+        assert_eq!(ApiResp::minimal_suc().to_json_minimal(), expected);
+
+        for resp in [
+            ApiResp::success(json!(1)),
+            ApiResp::success_msg("保存成功", None),
+            ApiResp::suc().with_trace_id("8f14e45f"),
+            ApiResp::suc().with_code(1),
+        ] {
+            assert_eq!(resp.to_json_minimal(), resp.to_json());
+        }
+    }
+
+    #[test]
+    fn test_to_json_headers_only() {
+        let resp = ApiResp::fail(500, "交易出错了").with_trace_id("8f14e45f");
+        let headers = resp.to_json_headers_only();
+        assert!(!headers.contains("8f14e45f"));
+        let orig = ApiResp::from_json(headers.as_str()).unwrap();
+        assert_eq!(orig, ApiResp::fail(500, "交易出错了"));
+    }
+}
@@ -0,0 +1,52 @@
+//! MessagePack格式的序列化支持，需启用`msgpack`特性。
+use crate::ApiResp;
+
+impl ApiResp {
+    /// 将响应对象序列化为MessagePack格式的字节数组。
+    ///
+    /// 结构体按属性名编码为映射，与JSON格式的属性保持一致。
+    ///
+    /// returns: `Result<Vec<u8>, rmp_serde::encode::Error>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let bytes = ApiResp::success(json!(vec![1,1,3,5])).to_msgpack().unwrap();
+    /// let resp = ApiResp::from_msgpack(&bytes).unwrap();
+    /// assert!(resp.is_success());
+    /// ```
+    pub fn to_msgpack(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec_named(self)
+    }
+
+    /// 从MessagePack格式的字节数组反序列化响应对象。
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes`: MessagePack格式的字节数组。
+    ///
+    /// returns: `Result<ApiResp, rmp_serde::decode::Error>`
+    pub fn from_msgpack(bytes: &[u8]) -> Result<ApiResp, rmp_serde::decode::Error> {
+        rmp_serde::from_slice(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_msgpack() {
+        let resp = ApiResp::success(json!({"color": "white", "weight": 10.5})).with_trace_id("8f14e45f");
+        let bytes = resp.to_msgpack().unwrap();
+        assert_eq!(ApiResp::from_msgpack(&bytes).unwrap(), resp);
+
+        let resp = ApiResp::error(-1, "交易出错了".to_string());
+        let bytes = resp.to_msgpack().unwrap();
+        assert!(bytes.len() < resp.to_json().len());
+        assert_eq!(ApiResp::from_msgpack(&bytes).unwrap(), resp);
+    }
+}
@@ -0,0 +1,32 @@
+//! 以换行分隔的JSON（NDJSON）格式输出大量数据。
+use std::io;
+
+use serde::Serialize;
+
+/// 将数据逐条序列化为一行JSON写入输出流，适用于导出大量数据而不必全部缓存在内存中。
+///
+/// 注意：输出结果不再包装为单个的`ApiResp`响应结构，每一行都是独立的JSON对象，
+/// 调用方需要按行解析。如需每行都带有响应结构，可以传入`ApiResp`的迭代器。
+///
+/// # Arguments
+///
+/// * `writer`: 输出流。
+/// * `items`: 待输出的数据。
+///
+/// returns: `io::Result<()>`
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// let mut buf = Vec::new();
+/// api_resp::write_ndjson(&mut buf, vec![json!({"id": 1}), json!({"id": 2})]).unwrap();
+/// assert_eq!(String::from_utf8(buf).unwrap(), "{\"id\":1}\n{\"id\":2}\n");
+/// ```
+pub fn write_ndjson<W: io::Write, I: IntoIterator<Item: Serialize>>(mut writer: W, items: I) -> io::Result<()> {
+    for item in items {
+        serde_json::to_writer(&mut writer, &item)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()
+}
@@ -0,0 +1,139 @@
+//! 分页查询的响应数据结构。
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use serde_json::json;
+
+use crate::ApiResp;
+
+/// 分页数据，作为分页响应的`data`。
+///
+/// 序列化结构示例：
+/// ```json
+/// {"list": [], "total": 0, "page": 1, "size": 10}
+/// ```
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PageData<T> {
+    /// 当前页的数据列表。
+    pub list: Vec<T>,
+    /// 总记录数。
+    pub total: u64,
+    /// 当前页码。
+    pub page: u64,
+    /// 每页记录数。
+    pub size: u64,
+}
+
+/// 游标分页数据，作为游标分页响应的`data`，适用于无法使用页码的无限滚动场景。
+///
+/// 序列化结构示例，没有下一页时不输出`next_cursor`：
+/// ```json
+/// {"items": [], "next_cursor": "c2VxPTEw", "has_more": true}
+/// ```
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CursorPage<T> {
+    /// 当前批次的数据列表。
+    pub items: Vec<T>,
+    /// 获取下一批数据的游标。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    /// 是否还有更多数据。
+    pub has_more: bool,
+}
+
+impl ApiResp {
+    /// 构造一个成功的分页响应对象。
+    ///
+    /// # Arguments
+    ///
+    /// * `data`: 当前页的数据列表。
+    /// * `total`: 总记录数。
+    /// * `page`: 当前页码。
+    /// * `size`: 每页记录数。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let resp = ApiResp::page(json!(vec![1,1,3,5]), 14, 1, 4);
+    /// let page = resp.get_page_data::<i32>().unwrap().unwrap();
+    /// assert_eq!(page.list, vec![1,1,3,5]);
+    /// assert_eq!(page.total, 14);
+    /// ```
+    pub fn page(data: serde_json::Value, total: u64, page: u64, size: u64) -> ApiResp {
+        ApiResp::success(json!({
+            "list": data,
+            "total": total,
+            "page": page,
+            "size": size,
+        }))
+    }
+
+    /// 将业务数据转换为分页数据。
+    ///
+    /// returns: `Result<Option<PageData<T>>, serde_json::Error>` 没有业务数据时返回`Ok(None)`。
+    pub fn get_page_data<T: DeserializeOwned>(&self) -> Result<Option<PageData<T>>, serde_json::Error> {
+        self.get_data_as()
+    }
+
+    /// 构造一个成功的游标分页响应对象，有下一批数据的游标时`has_more`为`true`。
+    ///
+    /// # Arguments
+    ///
+    /// * `items`: 当前批次的数据列表。
+    /// * `next_cursor`: 获取下一批数据的游标，没有更多数据时为`None`。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let resp = ApiResp::cursor_page(json!([1, 2]), Some("c2VxPTI=".to_string()));
+    /// let page = resp.get_cursor_page::<i32>().unwrap().unwrap();
+    /// assert!(page.has_more);
+    /// assert_eq!(page.next_cursor.as_deref(), Some("c2VxPTI="));
+    /// ```
+    pub fn cursor_page(items: serde_json::Value, next_cursor: Option<String>) -> ApiResp {
+        let has_more = next_cursor.is_some();
+        let mut data = json!({"items": items, "has_more": has_more});
+        if let Some(cursor) = next_cursor {
+            data["next_cursor"] = json!(cursor);
+        }
+        ApiResp::success(data)
+    }
+
+    /// 将业务数据转换为游标分页数据。
+    ///
+    /// returns: `Result<Option<CursorPage<T>>, serde_json::Error>` 没有业务数据时返回`Ok(None)`。
+    pub fn get_cursor_page<T: DeserializeOwned>(&self) -> Result<Option<CursorPage<T>>, serde_json::Error> {
+        self.get_data_as()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+    use super::*;
+
+    #[test]
+    fn test_cursor_page() {
+        let resp = ApiResp::cursor_page(json!([1, 2, 3]), Some("c2VxPTM=".to_string()));
+        let page = resp.get_cursor_page::<i32>().unwrap().unwrap();
+        assert_eq!(page.items, vec![1, 2, 3]);
+        assert!(page.has_more);
+
+        let resp = ApiResp::cursor_page(json!([]), None);
+        assert!(!resp.to_json().contains("next_cursor"));
+        let page = resp.get_cursor_page::<i32>().unwrap().unwrap();
+        assert!(page.items.is_empty() && !page.has_more && page.next_cursor.is_none());
+    }
+}
@@ -0,0 +1,28 @@
+//! 常用类型、特性与宏的集中导出。
+//!
+//! 通过`use api_resp::prelude::*;`一次性引入，无需逐个导入。
+//!
+//! # Examples
+//!
+//! ```
+//! use api_resp::prelude::*;
+//!
+//! fn find(id: u32) -> DaoResult {
+//!     ensure_resp!(id > 0, 400, "无效的编号");
+//!     Ok(api_success!({"id": id}))
+//! }
+//!
+//! assert!(find(1).unwrap().is_success());
+//! assert_eq!(find(0).unwrap().get_code(), 400);
+//! ```
+pub use crate::{
+    ApiError, ApiResp, ApiRespBuilder, ApiRespRef, BatchApiResp, CodeCategory, CodeStatusMap,
+    CursorPage, ErrorCode, ErrorDetail, FieldErrors, ItemResult, Message, MessageResolver, PageData,
+    TypedApiResp,
+};
+#[cfg(feature = "std")]
+pub use crate::{DaoResult, DaoResultExt, ResponseHook, TransformFuture, TransformResult};
+pub use crate::{
+    api_error, api_success, bail_resp, ensure_resp, impl_error_code, rollback, rollback_for_no_match,
+    rollback_for_no_match_sync, rollback_sync, rollback_with_msg, rollback_with_msg_sync,
+};
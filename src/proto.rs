@@ -0,0 +1,86 @@
+//! `ApiResp`在protobuf中的映射，需启用`prost`特性。
+//!
+//! 对应的protobuf定义：
+//! ```proto
+//! message ApiRespProto {
+//!   bool success = 1;
+//!   int32 code = 2;
+//!   string message = 3;
+//!   // 业务数据的JSON字符串。
+//!   optional string data = 4;
+//! }
+//! ```
+//! 只映射`success`、`code`、`message`、`data`四个基本属性，时间戳、跟踪标识等可选属性不参与转换。
+use alloc::string::{String, ToString};
+
+use crate::ApiResp;
+
+/// `ApiResp`的protobuf消息，`data`以JSON字符串的形式传输。
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ApiRespProto {
+    /// 执行是否成功
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    /// 响应代码
+    #[prost(int32, tag = "2")]
+    pub code: i32,
+    /// 响应附带消息，通常是错误提示信息。
+    #[prost(string, tag = "3")]
+    pub message: String,
+    /// 响应数据的JSON字符串。
+    #[prost(string, optional, tag = "4")]
+    pub data: Option<String>,
+}
+
+impl From<ApiResp> for ApiRespProto {
+    fn from(mut resp: ApiResp) -> Self {
+        let data = resp.take_data();
+        ApiRespProto {
+            success: resp.success,
+            code: resp.code,
+            message: resp.message,
+            data: data.map(|d| d.to_string()),
+        }
+    }
+}
+
+impl TryFrom<ApiRespProto> for ApiResp {
+    type Error = serde_json::Error;
+
+    fn try_from(proto: ApiRespProto) -> Result<Self, Self::Error> {
+        let data = match proto.data {
+            Some(d) => Some(serde_json::from_str(d.as_str())?),
+            None => None,
+        };
+        Ok(ApiResp {
+            success: proto.success,
+            code: proto.code,
+            message: proto.message,
+            data,
+            ..ApiResp::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_proto() {
+        for resp in [
+            ApiResp::success(json!({"color": "white", "weight": 10.5})),
+            ApiResp::suc(),
+            ApiResp::error(-1, "交易出错了".to_string()),
+        ] {
+            let bytes = ApiRespProto::from(resp.clone()).encode_to_vec();
+            let proto = ApiRespProto::decode(bytes.as_slice()).unwrap();
+            assert_eq!(ApiResp::try_from(proto).unwrap(), resp);
+        }
+
+        let proto = ApiRespProto { success: true, code: 0, message: "".to_string(), data: Some("{id: 1}".to_string()) };
+        assert!(ApiResp::try_from(proto).is_err());
+    }
+}
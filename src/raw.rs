@@ -0,0 +1,156 @@
+//! 原样透传的预序列化业务数据，需启用`raw_value`特性。
+use alloc::boxed::Box;
+use serde_json::value::RawValue;
+
+use crate::ApiResp;
+
+/// 预序列化的业务数据，按JSON文本比较是否相等。
+#[derive(Debug, Clone)]
+pub(crate) struct RawData(Box<RawValue>);
+
+impl RawData {
+    /// 预序列化的JSON文本。
+    pub(crate) fn get(&self) -> &str {
+        self.0.get()
+    }
+}
+
+impl PartialEq for RawData {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.get() == other.0.get()
+    }
+}
+
+impl ApiResp {
+    /// 以预序列化的JSON数据构造一个成功的响应对象，输出时业务数据按原样写出，不做解析与重新序列化。
+    ///
+    /// 适用于代理转发、缓存以及需要对数据签名的场景。所有序列化途径都以该数据作为`data`输出，
+    /// JSON格式按原样写出，MessagePack等二进制格式解析后输出。此时`get_data`返回`None`，可通过`get_raw_data`读取原始数据，
+    /// `into_parts`等取出业务数据的方法返回解析后的值。
+    ///
+    /// # Arguments
+    ///
+    /// * `raw`: 预序列化的JSON数据。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::value::RawValue;
+    /// let raw = RawValue::from_string(r#"{"b": 1,  "a": 2}"#.to_string()).unwrap();
+    /// let resp = ApiResp::success_raw_value(raw);
+    /// assert!(resp.to_json().contains(r#""data":{"b": 1,  "a": 2}"#));
+    /// ```
+    pub fn success_raw_value(raw: Box<RawValue>) -> ApiResp {
+        ApiResp {
+            raw_data: Some(RawData(raw)),
+            ..ApiResp::default()
+        }
+    }
+
+    /// 获取`success_raw_value`设置的预序列化业务数据。
+    pub fn get_raw_data(&self) -> Option<&RawValue> {
+        self.raw_data.as_ref().map(|r| r.0.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use super::*;
+
+    #[test]
+    fn test_raw_value() {
+        let text = r#"[{"z":1,"a":2.50}, {"y": "中"}]"#;
+        let resp = ApiResp::success_raw_value(RawValue::from_string(text.to_string()).unwrap()).with_trace_id("8f14e45f");
+        let json = resp.to_json();
+        assert!(json.contains(text));
+        assert_eq!(resp.get_raw_data().unwrap().get(), text);
+        assert!(resp.get_data().is_none());
+        assert!(resp.to_string().contains("hasData=true"));
+
+        // 按解析后的值比较，与普通的成功响应一致。
+        let orig = ApiResp::from_json(json.as_str()).unwrap();
+        let expected = ApiResp::success(serde_json::from_str(text).unwrap()).with_trace_id("8f14e45f");
+        assert_eq!(orig, expected);
+    }
+
+    #[test]
+    fn test_raw_value_every_path() {
+        let text = r#"{"id":7,"tags":["a"]}"#;
+        let resp = || ApiResp::success_raw_value(RawValue::from_string(text.to_string()).unwrap());
+        let parsed: serde_json::Value = serde_json::from_str(text).unwrap();
+
+        assert!(serde_json::to_string(&resp()).unwrap().contains(text));
+        #[cfg(feature = "std")]
+        {
+            use crate::TransformResult;
+            let r: crate::DaoResult = Ok(resp());
+            assert!(r.to_json_str("查询出错").contains(text));
+        }
+        assert_eq!(resp().into_parts().3, Some(parsed.clone()));
+        assert_eq!(resp().get_data_as::<serde_json::Value>().unwrap(), Some(parsed.clone()));
+        #[cfg(feature = "msgpack")]
+        assert_eq!(ApiResp::from_msgpack(&resp().to_msgpack().unwrap()).unwrap().get_data(), &Some(parsed));
+    }
+
+    #[test]
+    fn test_raw_value_redact() {
+        let raw = RawValue::from_string(r#"{"name":"tom","password":"123"}"#.to_string()).unwrap();
+        let json = ApiResp::success_raw_value(raw).redact(&["password"]).to_json();
+        assert!(!json.contains("123"));
+        assert!(json.contains(r#""password":"***""#));
+    }
+
+    #[test]
+    fn test_raw_value_mutators() {
+        use serde_json::json;
+        let resp = || ApiResp::success_raw_value(RawValue::from_string(r#"{"id":1}"#.to_string()).unwrap());
+        let expected = Some(json!({"id": 1, "name": "tom"}));
+
+        let mapped = resp().map_data(|mut d| {
+            d["name"] = json!("tom");
+            d
+        });
+        assert_eq!(ApiResp::from_json(&mapped.to_json()).unwrap().get_data(), &expected);
+
+        let merged = resp().merge_data(json!({"name": "tom"}));
+        assert_eq!(ApiResp::from_json(&merged.to_json()).unwrap().get_data(), &expected);
+
+        let mut changed = resp();
+        changed.get_data_mut().as_mut().unwrap()["name"] = json!("tom");
+        assert_eq!(ApiResp::from_json(&changed.to_json()).unwrap().get_data(), &expected);
+
+        let mut replaced = resp();
+        replaced.set_data(json!([1, 2]));
+        assert!(replaced.get_raw_data().is_none());
+        assert!(replaced.to_json().contains(r#""data":[1,2]"#));
+    }
+
+    #[test]
+    fn test_raw_value_scalars() {
+        let raw = |text: &str| ApiResp::success_raw_value(RawValue::from_string(text.to_string()).unwrap());
+        assert_eq!(raw("5").get_data_as_i64(), Some(5));
+        assert_eq!(raw("5").get_data_as_u64(), Some(5));
+        assert_eq!(raw("2.5").get_data_as_f64(), Some(2.5));
+        assert_eq!(raw("true").get_data_as_bool(), Some(true));
+        assert_eq!(raw(r#""tom""#).get_data_as_str().as_deref(), Some("tom"));
+        assert_eq!(raw(r#""a\nb""#).get_data_as_str().as_deref(), Some("a\nb"));
+        assert_eq!(raw("[1]").get_data_as_str(), None);
+        assert_eq!(raw(r#"{"rows_affected":3}"#).get_rows_affected(), Some(3));
+    }
+
+    #[test]
+    fn test_raw_value_stream_items() {
+        let resp = ApiResp::success_raw_value(RawValue::from_string(r#"[1, 2, "x"]"#.to_string()).unwrap());
+        let items: alloc::vec::Vec<Result<i32, _>> = resp.stream_data_items().collect();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[1].as_ref().unwrap(), &2);
+        assert!(items[2].is_err());
+
+        let resp = ApiResp::success_raw_value(RawValue::from_string(r#"{"a":1}"#.to_string()).unwrap());
+        assert_eq!(resp.stream_data_items::<i32>().filter(Result::is_err).count(), 1);
+    }
+}
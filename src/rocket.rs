@@ -0,0 +1,37 @@
+//! 为`rocket` 0.5框架提供的响应集成，需启用`rocket`特性。
+//!
+//! 启用后`ApiResp`可直接作为`rocket`路由函数的返回值，响应体为JSON字符串，`Content-Type`为`application/json`。
+use rocket::http::ContentType;
+use rocket::request::Request;
+use rocket::response::{self, Responder};
+
+use crate::ApiResp;
+
+impl<'r> Responder<'r, 'static> for ApiResp {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        (ContentType::JSON, self.to_json()).respond_to(req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rocket::http::Status;
+    use rocket::local::blocking::Client;
+    use rocket::{get, routes};
+    use super::*;
+
+    #[get("/")]
+    fn index() -> ApiResp {
+        ApiResp::error(-1, "交易出错了".to_string())
+    }
+
+    #[test]
+    fn test_respond_to() {
+        let client = Client::tracked(rocket::build().mount("/", routes![index])).unwrap();
+        let resp = client.get("/").dispatch();
+        assert_eq!(resp.status(), Status::Ok);
+        assert_eq!(resp.content_type(), Some(ContentType::JSON));
+        let orig: ApiResp = serde_json::from_str(resp.into_string().unwrap().as_str()).unwrap();
+        assert_eq!(orig.get_message(), "交易出错了");
+    }
+}
@@ -0,0 +1,61 @@
+//! 响应结构的JSON Schema生成，需启用`schemars`特性。
+use schemars::JsonSchema;
+
+use crate::{ApiResp, TypedApiResp};
+
+impl ApiResp {
+    /// 生成响应结构的JSON Schema，可用于网关校验或发布接口文档。
+    ///
+    /// `timestamp`、`trace_id`、`retry_after`、`meta`、`deprecated`、`idempotency_key`、`messages`等可选属性不在`required`之列。
+    ///
+    /// returns: serde_json::Value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let schema = ApiResp::json_schema();
+    /// assert_eq!(schema["title"], "ApiResp");
+    /// ```
+    pub fn json_schema() -> serde_json::Value {
+        schemars::schema_for!(ApiResp).to_value()
+    }
+}
+
+impl<T: JsonSchema> TypedApiResp<T> {
+    /// 生成带有业务数据类型的响应结构的JSON Schema，`data`的结构由`T`决定。
+    ///
+    /// returns: serde_json::Value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::{PageData, TypedApiResp};
+    /// let schema = TypedApiResp::<PageData<i32>>::json_schema();
+    /// assert!(schema["$defs"].get("PageData").is_some());
+    /// ```
+    pub fn json_schema() -> serde_json::Value {
+        schemars::schema_for!(TypedApiResp<T>).to_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_json_schema() {
+        let schema = ApiResp::json_schema();
+        let required = schema["required"].as_array().unwrap();
+        for key in ["code", "message"] {
+            assert!(required.contains(&json!(key)));
+        }
+        for key in ["timestamp", "trace_id", "retry_after", "meta", "deprecated", "idempotency_key", "messages"] {
+            assert!(!required.contains(&json!(key)), "{}", key);
+        }
+
+        let schema = TypedApiResp::<Vec<i32>>::json_schema();
+        assert_eq!(schema["properties"]["data"]["type"], json!(["array", "null"]));
+    }
+}
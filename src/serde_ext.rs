@@ -0,0 +1,166 @@
+//! 响应属性的自定义序列化与反序列化规则。
+use alloc::string::String;
+use core::fmt::Formatter;
+
+use serde::de::{Error, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "raw_value")]
+use serde_json::value::RawValue;
+
+use crate::{ApiResp, Message};
+
+/// 启用`success-as-int`特性后，`success`序列化为`1`或`0`。
+#[cfg(feature = "success-as-int")]
+pub(crate) fn serialize_success<S: Serializer>(success: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(u8::from(*success))
+}
+
+/// 兼容布尔值与`0`/`1`两种形式的`success`。
+pub(crate) fn deserialize_success<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    deserializer.deserialize_any(SuccessVisitor)
+}
+
+struct SuccessVisitor;
+
+impl<'de> Visitor<'de> for SuccessVisitor {
+    type Value = bool;
+
+    fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write_str("a boolean or 0/1")
+    }
+
+    fn visit_bool<E: Error>(self, v: bool) -> Result<bool, E> {
+        Ok(v)
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<bool, E> {
+        match v {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+        }
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<bool, E> {
+        match v {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(E::invalid_value(Unexpected::Unsigned(v), &self)),
+        }
+    }
+}
+
+/// 兼容数字与数字字符串两种形式的`code`，例如`0`与`"0"`。
+pub(crate) fn deserialize_code<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i32, D::Error> {
+    deserializer.deserialize_any(CodeVisitor)
+}
+
+struct CodeVisitor;
+
+impl<'de> Visitor<'de> for CodeVisitor {
+    type Value = i32;
+
+    fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write_str("an i32 or a numeric string")
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<i32, E> {
+        i32::try_from(v).map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<i32, E> {
+        i32::try_from(v).map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<i32, E> {
+        v.trim().parse().map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+/// 区分属性缺失与属性值为`null`：缺失时为`None`（需配合`#[serde(default)]`），为`null`时为`Some(None)`。
+pub(crate) fn deserialize_present<'de, D: Deserializer<'de>, T: Deserialize<'de>>(deserializer: D) -> Result<Option<Option<T>>, D::Error> {
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// 序列化`ApiResp`时使用的视图，属性与`ApiResp`的声明保持一致。
+///
+/// 携带预序列化的业务数据时，以其代替`data`输出，所有序列化途径的结果都一致。
+#[derive(Serialize)]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+struct ApiRespView<'a> {
+    #[cfg_attr(feature = "camel-case", serde(rename = "isSuccess"))]
+    #[cfg_attr(feature = "success-as-int", serde(serialize_with = "crate::serde_ext::serialize_success"))]
+    success: bool,
+    code: i32,
+    message: &'a str,
+    #[cfg_attr(feature = "omit-null-data", serde(skip_serializing_if = "DataView::is_none"))]
+    data: DataView<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timestamp: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trace_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retry_after: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<&'a serde_json::Map<String, serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deprecated: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    idempotency_key: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    messages: Option<&'a [Message]>,
+}
+
+/// 输出的业务数据，预序列化的数据优先。
+enum DataView<'a> {
+    Value(Option<&'a serde_json::Value>),
+    #[cfg(feature = "raw_value")]
+    Raw(&'a RawValue),
+}
+
+impl DataView<'_> {
+    #[cfg(feature = "omit-null-data")]
+    fn is_none(&self) -> bool {
+        matches!(self, DataView::Value(None))
+    }
+}
+
+impl Serialize for DataView<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            DataView::Value(data) => data.serialize(serializer),
+            // JSON等文本格式原样写出，MessagePack等二进制格式无法嵌入JSON文本，解析后再输出。
+            #[cfg(feature = "raw_value")]
+            DataView::Raw(raw) if serializer.is_human_readable() => raw.serialize(serializer),
+            #[cfg(feature = "raw_value")]
+            DataView::Raw(raw) => serde_json::from_str::<serde_json::Value>(raw.get())
+                .map_err(serde::ser::Error::custom)?
+                .serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for ApiResp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[cfg(feature = "raw_value")]
+        let data = match self.get_raw_data() {
+            Some(raw) => DataView::Raw(raw),
+            None => DataView::Value(self.data.as_ref()),
+        };
+        #[cfg(not(feature = "raw_value"))]
+        let data = DataView::Value(self.data.as_ref());
+        ApiRespView {
+            success: self.success,
+            code: self.code,
+            message: self.message.as_str(),
+            data,
+            timestamp: self.timestamp,
+            trace_id: self.trace_id.as_deref(),
+            retry_after: self.retry_after,
+            meta: self.meta.as_ref(),
+            deprecated: self.deprecated.as_deref(),
+            idempotency_key: self.idempotency_key.as_deref(),
+            messages: self.messages.as_deref(),
+        }.serialize(serializer)
+    }
+}
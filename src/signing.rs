@@ -0,0 +1,96 @@
+//! 响应签名，需启用`signing`特性。
+//!
+//! 签名以HMAC-SHA256计算，覆盖的是`to_json`输出的JSON字符串本身，接收方应对收到的原始文本验签，
+//! 而不是反序列化后再重新序列化。业务数据以[`success_raw_value`](ApiResp::success_raw_value)携带时原样输出，
+//! 可保证签名方与接收方看到的字节完全一致。
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use hmac::{Hmac, KeyInit, Mac};
+use sha2::Sha256;
+
+use crate::ApiResp;
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn mac(key: &[u8], json: &str) -> HmacSha256 {
+    // HMAC接受任意长度的密钥，这里不会出错。
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(json.as_bytes());
+    mac
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| hex.get(i..i + 2).and_then(|b| u8::from_str_radix(b, 16).ok()))
+        .collect()
+}
+
+impl ApiResp {
+    /// 计算响应对象JSON字符串的HMAC-SHA256签名。
+    ///
+    /// 签名覆盖的字符串与`to_json`的输出相同，但不会调用响应钩子。
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: 签名密钥。
+    ///
+    /// returns: String 小写十六进制的签名文本。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// let resp = ApiResp::suc();
+    /// let json = resp.to_json();
+    /// let sig = resp.sign(b"secret");
+    /// assert!(ApiResp::verify(&json, &sig, b"secret"));
+    /// assert!(!ApiResp::verify(&json, &sig, b"other"));
+    /// ```
+    pub fn sign(&self, key: &[u8]) -> String {
+        let digest = mac(key, &self.serialize_json()).finalize().into_bytes();
+        let mut sig = String::with_capacity(digest.len() * 2);
+        for b in digest.iter() {
+            let _ = write!(sig, "{:02x}", b);
+        }
+        sig
+    }
+
+    /// 校验收到的JSON字符串与签名是否匹配，比较时耗时恒定。
+    ///
+    /// # Arguments
+    ///
+    /// * `json`: 收到的原始JSON字符串。
+    /// * `sig`: 十六进制的签名文本，不区分大小写。
+    /// * `key`: 签名密钥。
+    ///
+    /// returns: bool 签名格式错误或不匹配时返回`false`。
+    pub fn verify(json: &str, sig: &str, key: &[u8]) -> bool {
+        match decode_hex(sig) {
+            Some(sig) => mac(key, json).verify_slice(&sig).is_ok(),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign() {
+        let resp = ApiResp::fail(409, "订单已支付");
+        let json = resp.to_json();
+        let sig = resp.sign(b"secret");
+        assert_eq!(sig.len(), 64);
+        assert!(ApiResp::verify(&json, &sig.to_uppercase(), b"secret"));
+        assert!(!ApiResp::verify(&json.replace("409", "200"), &sig, b"secret"));
+        assert!(!ApiResp::verify(&json, "zz", b"secret"));
+        assert!(!ApiResp::verify(&json, &sig[..62], b"secret"));
+    }
+}
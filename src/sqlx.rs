@@ -0,0 +1,43 @@
+//! `sqlx`错误的转换支持，需启用`sqlx`特性。
+use alloc::string::{String, ToString};
+
+use crate::{default_error_code, ApiResp};
+
+/// 未找到记录时使用的响应代码。
+const ROW_NOT_FOUND_CODE: i32 = 404;
+
+/// 将`sqlx`错误转换为失败响应，日志中记录原始错误。
+///
+/// `RowNotFound`转换为代码`404`、提示信息为“未找到目标记录”的响应；其余错误使用通用的错误代码与错误信息。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::ApiResp;
+/// let resp: ApiResp = sqlx::Error::RowNotFound.into();
+/// assert_eq!(resp.get_code(), 404);
+/// ```
+impl From<sqlx::Error> for ApiResp {
+    fn from(e: sqlx::Error) -> Self {
+        crate::__log_error!("{:?}", e);
+        match e {
+            sqlx::Error::RowNotFound => ApiResp::error(ROW_NOT_FOUND_CODE, String::from("未找到目标记录")),
+            e => ApiResp::error(default_error_code(), e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_sqlx_error() {
+        let resp = ApiResp::from(sqlx::Error::RowNotFound);
+        assert_eq!(resp, ApiResp::fail(404, "未找到目标记录"));
+
+        let resp = ApiResp::from(sqlx::Error::PoolTimedOut);
+        assert_eq!(resp.get_code(), default_error_code());
+        assert_eq!(resp.get_message(), &sqlx::Error::PoolTimedOut.to_string());
+    }
+}
@@ -0,0 +1,75 @@
+//! 以Server-Sent Events（SSE）格式输出响应对象，用于推送长时间任务的进度。
+use alloc::string::String;
+
+use crate::ApiResp;
+
+impl ApiResp {
+    /// 将响应对象格式化为一条SSE事件，`data`为`to_json`输出的JSON字符串。
+    ///
+    /// JSON字符串的每一行各写为一个`data`行，接收方按规范拼接后即得到原始的JSON字符串，
+    /// 因此携带多行的预序列化业务数据时也不会破坏事件格式。
+    ///
+    /// # Arguments
+    ///
+    /// * `event_name`: 事件名称，其中的换行符`\r`、`\n`会被去除，避免注入额外的事件属性。
+    ///
+    /// returns: String 以空行结尾的事件文本，可直接写入SSE响应流。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use serde_json::json;
+    /// let resp = ApiResp::success(json!({"percent": 40}));
+    /// let event = resp.to_sse_event("progress");
+    /// assert_eq!(event, format!("event: progress\ndata: {}\n\n", resp.to_json()));
+    /// ```
+    pub fn to_sse_event(&self, event_name: &str) -> String {
+        let json = self.to_json().replace("\r\n", "\n");
+        let mut event = String::with_capacity(event_name.len() + json.len() + 16);
+        event.push_str("event: ");
+        event.extend(event_name.chars().filter(|c| !matches!(c, '\r' | '\n')));
+        event.push('\n');
+        for line in json.split(['\r', '\n']) {
+            event.push_str("data: ");
+            event.push_str(line);
+            event.push('\n');
+        }
+        event.push('\n');
+        event
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sse_event() {
+        let event = ApiResp::fail(500, "第一行\n第二行").to_sse_event("failed");
+        assert!(event.starts_with("event: failed\ndata: {"));
+        assert!(event.ends_with("}\n\n"));
+        assert_eq!(event.matches('\n').count(), 3);
+    }
+
+    #[test]
+    fn test_sse_event_name_injection() {
+        let event = ApiResp::suc().to_sse_event("progress\r\ndata: forged\nid: 9");
+        assert!(event.starts_with("event: progressdata: forgedid: 9\ndata: {"));
+        assert_eq!(event.matches("\ndata: ").count(), 1);
+    }
+
+    #[cfg(feature = "raw_value")]
+    #[test]
+    fn test_sse_event_multiline_data() {
+        use alloc::string::ToString;
+        use alloc::vec::Vec;
+        let raw = serde_json::value::RawValue::from_string("{\n \"a\": 1\r\n}".to_string()).unwrap();
+        let resp = ApiResp::success_raw_value(raw);
+        let event = resp.to_sse_event("progress");
+        let lines: Vec<&str> = event.lines().collect();
+        assert!(lines[1..lines.len() - 1].iter().all(|l| l.starts_with("data: ")));
+        let data: Vec<&str> = lines[1..].iter().filter_map(|l| l.strip_prefix("data: ")).collect();
+        assert_eq!(data.join("\n"), resp.to_json().replace("\r\n", "\n"));
+    }
+}
@@ -0,0 +1,186 @@
+//! 响应代码与HTTP状态码的对照表。
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+use crate::{default_error_code, ApiResp};
+
+/// 响应代码与HTTP状态码的对照表。
+///
+/// 查找顺序：
+/// 1. 对照表中登记的代码；
+/// 2. 类别与`success`一致的HTTP状态码原样使用，即成功响应的`200`～`299`，失败响应的`400`～`599`；
+/// 3. 其余代码，成功响应（包括代码为[`default_success_code`](crate::default_success_code)的响应）使用`200`，
+///    失败响应中代码为[`default_error_code`]的使用`500`，其余使用兜底状态码，默认为`500`。
+///
+/// 通过[`CodeStatusMap::install`]在启动时注册后，`axum`与`actix`的响应集成按对照表设置HTTP状态码；
+/// 未注册时保持原有行为。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::{ApiResp, CodeStatusMap};
+///
+/// let map = CodeStatusMap::builder()
+///     .map(10001, 401)
+///     .fallback(503)
+///     .build();
+/// assert_eq!(map.status_of(&ApiResp::fail(10001, "登录已过期")), 401);
+/// assert_eq!(map.status_of(&ApiResp::fail(404, "未找到目标记录")), 404);
+/// assert_eq!(map.status_of(&ApiResp::fail(20001, "库存不足")), 503);
+/// assert_eq!(map.status_of(&ApiResp::suc()), 200);
+/// assert_eq!(map.status_of(&ApiResp::fail(200, "状态不一致")), 503);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeStatusMap {
+    statuses: BTreeMap<i32, u16>,
+    fallback: u16,
+}
+
+impl Default for CodeStatusMap {
+    fn default() -> Self {
+        CodeStatusMap::builder().build()
+    }
+}
+
+impl CodeStatusMap {
+    /// 创建对照表的构造器，尚未登记任何代码，兜底状态码为`500`。
+    ///
+    /// returns: CodeStatusMapBuilder
+    pub fn builder() -> CodeStatusMapBuilder {
+        CodeStatusMapBuilder { statuses: BTreeMap::new(), fallback: 500 }
+    }
+
+    /// 查找响应对应的HTTP状态码。
+    ///
+    /// # Arguments
+    ///
+    /// * `resp`: 响应对象。
+    ///
+    /// returns: u16
+    pub fn status_of(&self, resp: &ApiResp) -> u16 {
+        if let Some(status) = self.statuses.get(&resp.code) {
+            return *status;
+        }
+        match (resp.success, resp.code) {
+            (true, 200..=299) | (false, 400..=599) => resp.code as u16,
+            (true, _) => 200,
+            (false, code) if code == default_error_code() => 500,
+            (false, _) => self.fallback,
+        }
+    }
+
+    /// 注册为全局的对照表，只能注册一次。
+    ///
+    /// returns: `Result<(), CodeStatusMap>` 已经注册过时返回`Err`，携带本次传入的对照表。
+    #[cfg(feature = "std")]
+    pub fn install(self) -> Result<(), CodeStatusMap> {
+        CODE_STATUS_MAP.set(self)
+    }
+
+    /// 获取已注册的全局对照表。
+    #[cfg(feature = "std")]
+    pub fn installed() -> Option<&'static CodeStatusMap> {
+        CODE_STATUS_MAP.get()
+    }
+}
+
+#[cfg(feature = "std")]
+static CODE_STATUS_MAP: OnceLock<CodeStatusMap> = OnceLock::new();
+
+/// [`CodeStatusMap`]的构造器。
+#[derive(Debug)]
+pub struct CodeStatusMapBuilder {
+    statuses: BTreeMap<i32, u16>,
+    fallback: u16,
+}
+
+impl CodeStatusMapBuilder {
+    /// 登记响应代码对应的HTTP状态码，可覆盖默认的对照关系。
+    ///
+    /// HTTP状态码不在`100`～`599`之间时触发panic。
+    pub fn map(mut self, code: i32, status: u16) -> CodeStatusMapBuilder {
+        self.statuses.insert(code, check_status(status));
+        self
+    }
+
+    /// 设置未登记的失败代码使用的HTTP状态码。
+    ///
+    /// HTTP状态码不在`100`～`599`之间时触发panic。
+    pub fn fallback(mut self, status: u16) -> CodeStatusMapBuilder {
+        self.fallback = check_status(status);
+        self
+    }
+
+    /// 构造对照表。
+    ///
+    /// returns: CodeStatusMap
+    pub fn build(self) -> CodeStatusMap {
+        CodeStatusMap {
+            statuses: self.statuses,
+            fallback: self.fallback,
+        }
+    }
+}
+
+fn check_status(status: u16) -> u16 {
+    assert!((100..=599).contains(&status), "无效的HTTP状态码：{}", status);
+    status
+}
+
+impl ApiResp {
+    /// 获取响应对应的HTTP状态码，使用已注册的全局对照表，未注册时使用默认的对照表。
+    ///
+    /// returns: u16
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// assert_eq!(ApiResp::suc().http_status(), 200);
+    /// assert_eq!(ApiResp::fail(-1, "交易出错了").http_status(), 500);
+    /// assert_eq!(ApiResp::fail(429, "请求过于频繁").http_status(), 429);
+    /// ```
+    pub fn http_status(&self) -> u16 {
+        #[cfg(feature = "std")]
+        if let Some(map) = CodeStatusMap::installed() {
+            return map.status_of(self);
+        }
+        CodeStatusMap::default().status_of(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_status_map() {
+        let map = CodeStatusMap::default();
+        assert_eq!(map.status_of(&ApiResp::suc()), 200);
+        assert_eq!(map.status_of(&ApiResp::fail(-1, "交易出错了")), 500);
+        assert_eq!(map.status_of(&ApiResp::fail(404, "未找到目标记录")), 404);
+        assert_eq!(map.status_of(&ApiResp::fail(20001, "库存不足")), 500);
+        assert_eq!(map.status_of(&ApiResp::suc().with_code(1)), 200);
+        assert_eq!(map.status_of(&ApiResp::success_coded(100, serde_json::json!(1))), 200);
+        assert_eq!(map.status_of(&ApiResp::success_coded(201, serde_json::json!(1))), 201);
+        assert_eq!(map.status_of(&ApiResp::fail(200, "状态不一致")), 500);
+        assert_eq!(map.status_of(&ApiResp::fail(302, "状态不一致")), 500);
+
+        let map = CodeStatusMap::builder().map(0, 204).map(404, 200).build();
+        assert_eq!(map.status_of(&ApiResp::suc()), 204);
+        assert_eq!(map.status_of(&ApiResp::fail(404, "未找到目标记录")), 200);
+    }
+
+    #[test]
+    #[should_panic(expected = "无效的HTTP状态码：600")]
+    fn test_invalid_status() {
+        CodeStatusMap::builder().map(10001, 600);
+    }
+
+    #[test]
+    #[should_panic(expected = "无效的HTTP状态码：42")]
+    fn test_invalid_fallback() {
+        CodeStatusMap::builder().fallback(42);
+    }
+}
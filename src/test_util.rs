@@ -0,0 +1,63 @@
+//! 供测试使用的断言方法，需启用`test-util`特性。
+use crate::ApiResp;
+
+impl ApiResp {
+    /// 断言为成功响应，否则以实际的代码与消息触发panic。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// ApiResp::suc().assert_success();
+    /// ```
+    ///
+    /// ```should_panic
+    /// use api_resp::ApiResp;
+    /// ApiResp::fail(500, "交易出错了").assert_success();
+    /// ```
+    #[track_caller]
+    pub fn assert_success(&self) {
+        if !self.success {
+            panic!("期望成功响应，实际为失败响应：code={}, message=\"{}\"", self.code, self.message);
+        }
+    }
+
+    /// 断言为指定代码的失败响应，否则以实际的响应状态触发panic。
+    ///
+    /// # Arguments
+    ///
+    /// * `code`: 期望的失败代码。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// ApiResp::fail(404, "未找到目标记录").assert_error_code(404);
+    /// ```
+    #[track_caller]
+    pub fn assert_error_code(&self, code: i32) {
+        if self.success {
+            panic!("期望代码为{}的失败响应，实际为成功响应：code={}", code, self.code);
+        }
+        if self.code != code {
+            panic!("期望代码为{}的失败响应，实际代码为{}：message=\"{}\"", code, self.code, self.message);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assert_helpers() {
+        ApiResp::suc().assert_success();
+        ApiResp::fail(404, "未找到目标记录").assert_error_code(404);
+    }
+
+    #[test]
+    #[should_panic(expected = "实际代码为500")]
+    fn test_assert_error_code_mismatch() {
+        ApiResp::fail(500, "交易出错了").assert_error_code(404);
+    }
+}
@@ -0,0 +1,125 @@
+//! 为`tonic` 0.14提供的gRPC状态转换，需启用`tonic`特性。
+//!
+//! 失败响应的`code`按对照表转换为gRPC的[`Code`]，`message`作为状态的提示信息。
+//! 默认对照表参照HTTP状态码的语义，可以通过[`set_code_mapper`]在启动时注册自定义的转换规则。
+use std::sync::OnceLock;
+
+use tonic::{Code, Status};
+
+use crate::{default_error_code, ApiResp};
+
+/// 自定义的响应代码转换规则，返回`None`时使用默认对照表。
+pub type CodeMapper = fn(i32) -> Option<Code>;
+
+static CODE_MAPPER: OnceLock<CodeMapper> = OnceLock::new();
+
+/// 注册自定义的响应代码转换规则，只能注册一次。
+///
+/// # Arguments
+///
+/// * `mapper`: 转换规则。
+///
+/// returns: `Result<(), CodeMapper>` 已经注册过时返回`Err`，携带本次传入的规则。
+///
+/// # Examples
+///
+/// ```
+/// use tonic::Code;
+/// api_resp::tonic::set_code_mapper(|code| match code {
+///     1001 => Some(Code::FailedPrecondition),
+///     _ => None,
+/// }).unwrap();
+/// assert_eq!(api_resp::tonic::grpc_code(1001), Code::FailedPrecondition);
+/// ```
+pub fn set_code_mapper(mapper: CodeMapper) -> Result<(), CodeMapper> {
+    CODE_MAPPER.set(mapper)
+}
+
+/// 将响应代码转换为gRPC的状态代码。
+///
+/// 默认对照表：
+///
+/// | 响应代码 | gRPC代码 |
+/// | --- | --- |
+/// | `0` | `Ok` |
+/// | 默认错误代码（见[`default_error_code`]）、`500` | `Internal` |
+/// | `400` | `InvalidArgument` |
+/// | `401` | `Unauthenticated` |
+/// | `403` | `PermissionDenied` |
+/// | `404` | `NotFound` |
+/// | `409` | `AlreadyExists` |
+/// | `429` | `ResourceExhausted` |
+/// | `501` | `Unimplemented` |
+/// | `503` | `Unavailable` |
+/// | `504` | `DeadlineExceeded` |
+/// | 其他 | `Unknown` |
+pub fn grpc_code(code: i32) -> Code {
+    if let Some(c) = CODE_MAPPER.get().and_then(|mapper| mapper(code)) {
+        return c;
+    }
+    match code {
+        0 => Code::Ok,
+        c if c == default_error_code() => Code::Internal,
+        500 => Code::Internal,
+        400 => Code::InvalidArgument,
+        401 => Code::Unauthenticated,
+        403 => Code::PermissionDenied,
+        404 => Code::NotFound,
+        409 => Code::AlreadyExists,
+        429 => Code::ResourceExhausted,
+        501 => Code::Unimplemented,
+        503 => Code::Unavailable,
+        504 => Code::DeadlineExceeded,
+        _ => Code::Unknown,
+    }
+}
+
+/// 成功响应转换为`Ok`；失败响应按[`grpc_code`]转换，转换结果为`Ok`时（例如代码为`0`的失败响应）改为`Unknown`，
+/// 失败响应不会转换为`Ok`状态。
+impl From<ApiResp> for Status {
+    fn from(resp: ApiResp) -> Self {
+        let code = match resp.success {
+            true => Code::Ok,
+            false => match grpc_code(resp.code) {
+                Code::Ok => Code::Unknown,
+                c => c,
+            },
+        };
+        Status::new(code, resp.message)
+    }
+}
+
+impl ApiResp {
+    /// 转换为gRPC调用结果，成功时返回业务数据，失败时返回对应的gRPC状态。
+    ///
+    /// returns: `Result<serde_json::Value, Status>` 成功响应没有业务数据时返回`Value::Null`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::ApiResp;
+    /// use tonic::Code;
+    /// let status = ApiResp::error(404, "未找到目标记录".to_string()).into_status().unwrap_err();
+    /// assert_eq!(status.code(), Code::NotFound);
+    /// ```
+    pub fn into_status(mut self) -> Result<serde_json::Value, Status> {
+        if self.success {
+            Ok(self.take_data().unwrap_or_default())
+        } else {
+            Err(self.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failure_never_ok() {
+        let status = ApiResp::error(0, "代码与结果不一致".to_string()).into_status().unwrap_err();
+        assert_eq!(status.code(), Code::Unknown);
+        assert_eq!(status.message(), "代码与结果不一致");
+        assert_eq!(Status::from(ApiResp::suc()).code(), Code::Ok);
+    }
+}
@@ -0,0 +1,235 @@
+//! 为`tower`服务栈提供的响应封装中间件，需启用`tower`特性。
+//!
+//! [`ResponseEnvelopeLayer`]将内层服务返回的JSON数据统一封装为`ApiResp`的JSON结构，处理函数无需各自构造响应对象；
+//! 非JSON、流式以及不能携带响应体的响应原样返回。
+use std::boxed::Box;
+use std::error::Error;
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use http::header::{HeaderValue, CONTENT_LENGTH, CONTENT_TYPE};
+use http::response::Parts;
+use http::{Response, StatusCode};
+use http_body::Body;
+use http_body_util::{BodyExt, Either, Full};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::{default_error_code, ApiResp};
+
+/// 将响应体封装为`ApiResp`的中间件。
+///
+/// 只改写以下两类响应，其余响应（包括流式响应与二进制内容）原样返回，响应体不做任何读取：
+/// * `Content-Type`为`application/json`（可带`charset`等参数）的响应；
+/// * 没有`Content-Type`且响应体为空的响应，例如路由未匹配时返回的空`404`。
+///
+/// 无论`Content-Type`如何，1xx、3xx（包括`304`）与`204`响应都原样返回，这些响应不能携带响应体或不表示处理结果。
+///
+/// 改写规则：
+/// * 响应体本身已经是`ApiResp`的JSON结构时（例如处理函数直接返回了`ApiResp`），原样返回，不会重复封装；
+/// * 2xx响应封装为成功响应，响应体作为业务数据，空响应体没有业务数据，不是合法JSON时以文本作为业务数据；
+/// * 4xx、5xx响应封装为失败响应，代码为HTTP状态码，消息为响应体文本，为空时使用状态码的标准描述。
+///
+/// 改写后HTTP状态码保持不变，`Content-Type`设置为`application/json`。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::tower::ResponseEnvelopeLayer;
+/// use tower_layer::Layer;
+///
+/// # #[derive(Clone)]
+/// # struct Handler;
+/// let service = ResponseEnvelopeLayer.layer(Handler);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResponseEnvelopeLayer;
+
+impl<S> Layer<S> for ResponseEnvelopeLayer {
+    type Service = ResponseEnvelope<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ResponseEnvelope { inner }
+    }
+}
+
+/// [`ResponseEnvelopeLayer`]生成的服务。
+#[derive(Debug, Clone)]
+pub struct ResponseEnvelope<S> {
+    inner: S,
+}
+
+impl<S, Req, B> Service<Req> for ResponseEnvelope<S>
+where
+    S: Service<Req, Response = Response<B>>,
+    S::Future: Send + 'static,
+    B: Body<Data = Bytes> + Send + 'static,
+    B::Error: Display + Into<Box<dyn Error + Send + Sync>>,
+{
+    type Response = Response<Either<Full<Bytes>, B>>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        let fut = self.inner.call(req);
+        Box::pin(async move {
+            let (mut parts, body) = fut.await?.into_parts();
+            if !should_wrap(&parts, &body) {
+                return Ok(Response::from_parts(parts, Either::Right(body)));
+            }
+            let bytes = match body.collect().await {
+                Ok(collected) => collected.to_bytes(),
+                Err(e) => {
+                    crate::__log_error!("读取响应体时出错！{}", e);
+                    parts.status = StatusCode::INTERNAL_SERVER_ERROR;
+                    Bytes::from(ApiResp::fail(default_error_code(), "处理响应结果时出错！").to_json())
+                }
+            };
+            let bytes = if ApiResp::from_slice(&bytes).is_ok() {
+                bytes
+            } else {
+                Bytes::from(envelope(parts.status, &bytes).to_json())
+            };
+            parts.headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+            parts.headers.remove(CONTENT_LENGTH);
+            Ok(Response::from_parts(parts, Either::Left(Full::new(bytes))))
+        })
+    }
+}
+
+/// 是否需要改写响应，规则见[`ResponseEnvelopeLayer`]。
+fn should_wrap<B: Body>(parts: &Parts, body: &B) -> bool {
+    let status = parts.status;
+    if status.is_informational() || status.is_redirection() || status == StatusCode::NO_CONTENT {
+        return false;
+    }
+    match parts.headers.get(CONTENT_TYPE) {
+        Some(content_type) => is_json(content_type),
+        None => body.is_end_stream() || body.size_hint().exact() == Some(0),
+    }
+}
+
+/// `Content-Type`是否为`application/json`，忽略大小写与参数。
+fn is_json(content_type: &HeaderValue) -> bool {
+    content_type.to_str()
+        .ok()
+        .and_then(|v| v.split(';').next())
+        .is_some_and(|essence| essence.trim().eq_ignore_ascii_case("application/json"))
+}
+
+/// 将原始的响应体封装为响应对象。
+fn envelope(status: StatusCode, bytes: &[u8]) -> ApiResp {
+    if status.is_success() {
+        if bytes.is_empty() {
+            ApiResp::suc()
+        } else {
+            match serde_json::from_slice(bytes) {
+                Ok(data) => ApiResp::success(data),
+                Err(_) => ApiResp::success(serde_json::Value::String(String::from_utf8_lossy(bytes).into_owned())),
+            }
+        }
+    } else if bytes.is_empty() {
+        ApiResp::fail(i32::from(status.as_u16()), status.canonical_reason().unwrap_or_default())
+    } else {
+        ApiResp::fail(i32::from(status.as_u16()), String::from_utf8_lossy(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+    use std::future::{ready, Ready};
+    use std::pin::pin;
+    use std::task::Waker;
+    use serde_json::json;
+    use super::*;
+
+    /// 返回固定响应的内层服务。
+    #[derive(Clone)]
+    struct Fixed(StatusCode, Option<&'static str>, &'static str);
+
+    impl Service<()> for Fixed {
+        type Response = Response<Full<Bytes>>;
+        type Error = Infallible;
+        type Future = Ready<Result<Self::Response, Infallible>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _req: ()) -> Self::Future {
+            let mut res = Response::new(Full::new(Bytes::from_static(self.2.as_bytes())));
+            *res.status_mut() = self.0;
+            if let Some(content_type) = self.1 {
+                res.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+            }
+            ready(Ok(res))
+        }
+    }
+
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let mut fut = pin!(fut);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+                return out;
+            }
+        }
+    }
+
+    /// 调用封装后的服务，返回状态码、`Content-Type`与响应体。
+    fn call(status: StatusCode, content_type: Option<&'static str>, body: &'static str) -> (StatusCode, Option<String>, Bytes) {
+        let mut service = ResponseEnvelopeLayer.layer(Fixed(status, content_type, body));
+        let res = block_on(service.call(())).unwrap();
+        let content_type = res.headers().get(CONTENT_TYPE).map(|v| v.to_str().unwrap().to_string());
+        (res.status(), content_type, block_on(res.into_body().collect()).unwrap().to_bytes())
+    }
+
+    fn call_wrapped(status: StatusCode, content_type: Option<&'static str>, body: &'static str) -> ApiResp {
+        let (res_status, res_type, bytes) = call(status, content_type, body);
+        assert_eq!(res_status, status);
+        assert_eq!(res_type.as_deref(), Some("application/json"));
+        ApiResp::from_slice(&bytes).unwrap()
+    }
+
+    #[test]
+    fn test_response_envelope() {
+        const JSON: Option<&str> = Some("application/json");
+        assert_eq!(call_wrapped(StatusCode::OK, JSON, r#"{"id":1}"#), ApiResp::success(json!({"id": 1})));
+        assert_eq!(call_wrapped(StatusCode::OK, Some("Application/JSON; charset=utf-8"), "[1]"), ApiResp::success(json!([1])));
+        assert_eq!(call_wrapped(StatusCode::OK, None, ""), ApiResp::suc());
+        assert_eq!(call_wrapped(StatusCode::NOT_FOUND, None, ""), ApiResp::fail(404, "Not Found"));
+        assert_eq!(call_wrapped(StatusCode::BAD_REQUEST, JSON, "缺少参数"), ApiResp::fail(400, "缺少参数"));
+
+        // 已经封装过的响应保持不变。
+        let json = ApiResp::fail(409, "记录已存在").to_json();
+        let resp = call_wrapped(StatusCode::OK, JSON, Box::leak(json.into_boxed_str()));
+        assert_eq!(resp, ApiResp::fail(409, "记录已存在"));
+    }
+
+    #[test]
+    fn test_response_passthrough() {
+        let cases = [
+            (StatusCode::NO_CONTENT, Some("application/json"), ""),
+            (StatusCode::NOT_MODIFIED, None, ""),
+            (StatusCode::FOUND, Some("application/json"), ""),
+            (StatusCode::OK, Some("text/plain"), "pong"),
+            (StatusCode::OK, Some("text/event-stream"), "data: 1\n\n"),
+            (StatusCode::OK, Some("application/vnd.api+json"), r#"{"data":null}"#),
+            (StatusCode::OK, None, "\u{0}\u{1}"),
+        ];
+        for (status, content_type, body) in cases {
+            let (res_status, res_type, bytes) = call(status, content_type, body);
+            assert_eq!(res_status, status);
+            assert_eq!(res_type.as_deref(), content_type);
+            assert_eq!(bytes, body.as_bytes());
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! 按名称注册的业务数据转换规则，用于在多个服务间复用对`data`的后处理。
+use std::boxed::Box;
+use std::collections::BTreeMap;
+use std::string::String;
+use std::sync::RwLock;
+
+use crate::ApiResp;
+
+/// 业务数据转换规则。
+pub type Transformer = Box<dyn Fn(&mut serde_json::Value) + Send + Sync>;
+
+/// 按名称保存的业务数据转换规则。
+///
+/// 可以单独创建使用，也可以通过[`register_transformer`]注册到全局，由[`ApiResp::apply_transformer`]调用。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::{ApiResp, TransformerRegistry};
+/// use serde_json::json;
+///
+/// let mut registry = TransformerRegistry::new();
+/// registry.register("round_money", |v: &mut serde_json::Value| {
+///     if let Some(n) = v["amount"].as_f64() {
+///         v["amount"] = json!((n * 100.0).round() / 100.0);
+///     }
+/// });
+/// let resp = registry.apply(ApiResp::success(json!({"amount": 10.456})), "round_money");
+/// assert_eq!(resp.get_data(), &Some(json!({"amount": 10.46})));
+/// ```
+#[derive(Default)]
+pub struct TransformerRegistry {
+    transformers: BTreeMap<String, Transformer>,
+}
+
+impl TransformerRegistry {
+    pub const fn new() -> TransformerRegistry {
+        TransformerRegistry { transformers: BTreeMap::new() }
+    }
+
+    /// 注册转换规则，同名的规则将被替换。
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: 规则名称。
+    /// * `f`: 转换规则，直接修改业务数据。
+    pub fn register(&mut self, name: impl Into<String>, f: impl Fn(&mut serde_json::Value) + Send + Sync + 'static) {
+        self.transformers.insert(name.into(), Box::new(f));
+    }
+
+    /// 是否已注册指定名称的规则。
+    pub fn contains(&self, name: &str) -> bool {
+        self.transformers.contains_key(name)
+    }
+
+    /// 使用指定名称的规则处理响应的业务数据。
+    ///
+    /// # Arguments
+    ///
+    /// * `resp`: 响应对象。
+    /// * `name`: 规则名称，未注册时记录警告日志，业务数据保持不变。
+    ///
+    /// returns: ApiResp
+    pub fn apply(&self, mut resp: ApiResp, name: &str) -> ApiResp {
+        match self.transformers.get(name) {
+            Some(f) => {
+                if let Some(d) = resp.get_data_mut() {
+                    f(d);
+                }
+            }
+            None => crate::__log_warn!("未注册的数据转换规则：{}", name),
+        }
+        resp
+    }
+}
+
+static TRANSFORMERS: RwLock<TransformerRegistry> = RwLock::new(TransformerRegistry::new());
+
+/// 向全局注册业务数据转换规则，同名的规则将被替换。
+///
+/// # Arguments
+///
+/// * `name`: 规则名称。
+/// * `f`: 转换规则，直接修改业务数据。
+///
+/// # Examples
+///
+/// ```
+/// use api_resp::ApiResp;
+/// use serde_json::json;
+///
+/// api_resp::register_transformer("mask_emails", |v: &mut serde_json::Value| {
+///     if let Some(email) = v["email"].as_str() {
+///         let domain = email.split('@').nth(1).unwrap_or_default();
+///         v["email"] = json!(format!("***@{}", domain));
+///     }
+/// });
+/// let resp = ApiResp::success(json!({"email": "tom@example.com"})).apply_transformer("mask_emails");
+/// assert_eq!(resp.get_data(), &Some(json!({"email": "***@example.com"})));
+/// ```
+pub fn register_transformer(name: impl Into<String>, f: impl Fn(&mut serde_json::Value) + Send + Sync + 'static) {
+    TRANSFORMERS.write().unwrap_or_else(|e| e.into_inner()).register(name, f);
+}
+
+impl ApiResp {
+    /// 使用全局注册的规则处理业务数据。
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: 规则名称，未注册时记录警告日志，业务数据保持不变。
+    ///
+    /// returns: ApiResp
+    pub fn apply_transformer(self, name: &str) -> ApiResp {
+        TRANSFORMERS.read().unwrap_or_else(|e| e.into_inner()).apply(self, name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_transformer() {
+        register_transformer("drop_internal", |v: &mut serde_json::Value| {
+            if let Some(obj) = v.as_object_mut() {
+                obj.remove("internal");
+            }
+        });
+        let resp = ApiResp::success(json!({"id": 1, "internal": true})).apply_transformer("drop_internal");
+        assert_eq!(resp.get_data(), &Some(json!({"id": 1})));
+
+        // 未注册的规则不改变业务数据。
+        let resp = ApiResp::success(json!({"id": 1})).apply_transformer("not_exists");
+        assert_eq!(resp.get_data(), &Some(json!({"id": 1})));
+        assert!(ApiResp::suc().apply_transformer("drop_internal").get_data().is_none());
+    }
+}
@@ -0,0 +1,153 @@
+//! 带有业务数据类型参数的API接口响应数据结构。
+use alloc::string::{String, ToString};
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+
+use crate::{default_error_code, default_success_code, to_json_or_error, ApiResp};
+
+/// 带有业务数据类型的API接口响应数据结构。
+///
+/// 与[`ApiResp`]的序列化结构完全一致，区别在于`data`的类型在编译期即可确定，无需通过`json!()`转换。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel-case", serde(rename_all = "camelCase"))]
+pub struct TypedApiResp<T> {
+    /// 执行是否成功
+    #[cfg_attr(feature = "camel-case", serde(rename = "isSuccess"))]
+    #[cfg_attr(feature = "success-as-int", serde(serialize_with = "crate::serde_ext::serialize_success"))]
+    #[serde(deserialize_with = "crate::serde_ext::deserialize_success")]
+    #[cfg_attr(all(feature = "schemars", feature = "success-as-int"), schemars(with = "u8"))]
+    success: bool,
+    /// 响应代码，反序列化时同时接受数字与数字字符串。
+    #[serde(deserialize_with = "crate::serde_ext::deserialize_code")]
+    code: i32,
+    /// 响应附带消息，通常是错误提示信息。
+    message: String,
+    /// 响应数据。启用`omit-null-data`特性后，没有业务数据时不输出该属性。
+    #[cfg_attr(feature = "omit-null-data", serde(skip_serializing_if = "Option::is_none"))]
+    data: Option<T>,
+}
+
+impl<T> TypedApiResp<T> where T: Serialize + DeserializeOwned {
+    pub fn is_success(&self) -> bool { self.success }
+
+    pub fn get_code(&self) -> i32 { self.code }
+
+    pub fn get_message(&self) -> &String { &self.message }
+
+    pub fn get_data(&self) -> &Option<T> { &self.data }
+
+    /// 序列化为JSON字符串，注册了[`ResponseHook`](crate::ResponseHook)时，以转换后的[`ApiResp`]调用钩子。
+    ///
+    /// returns: String
+    pub fn to_json(&self) -> String {
+        #[cfg(feature = "std")]
+        crate::hook::on_response_with(|| ApiResp {
+            success: self.success,
+            code: self.code,
+            message: self.message.clone(),
+            data: self.data.as_ref().and_then(|d| serde_json::to_value(d).ok()),
+            ..ApiResp::default()
+        });
+        to_json_or_error(self, Some(self.code), Some(self.success))
+    }
+
+    /// 转换为不带类型参数的响应对象，业务数据被转换为`serde_json::Value`。
+    ///
+    /// returns: ApiResp 业务数据无法转换时，返回失败响应。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::TypedApiResp;
+    /// let resp = TypedApiResp::success(vec![1, 1, 3, 5]).into_untyped();
+    /// assert!(resp.is_success());
+    /// ```
+    pub fn into_untyped(self) -> ApiResp {
+        let data = match self.data {
+            Some(d) => match serde_json::to_value(d) {
+                Ok(v) => Some(v),
+                Err(e) => {
+                    crate::__log_error!("转换业务数据时出错！{}", e);
+                    return ApiResp::error(default_error_code(), "处理响应结果时出错！".to_string());
+                }
+            },
+            None => None,
+        };
+        ApiResp {
+            success: self.success,
+            code: self.code,
+            message: self.message,
+            data,
+            ..ApiResp::default()
+        }
+    }
+}
+
+impl<T> TypedApiResp<T> where T: Serialize + DeserializeOwned {
+    /// 构造一个成功的响应对象。
+    ///
+    /// # Arguments
+    ///
+    /// * `data`: 业务数据。
+    ///
+    /// returns: `TypedApiResp<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::TypedApiResp;
+    /// let resp: TypedApiResp<Vec<i32>> = TypedApiResp::success(vec![1,1,3,5]);
+    /// ```
+    pub fn success(data: T) -> TypedApiResp<T> {
+        TypedApiResp {
+            success: true,
+            code: default_success_code(),
+            message: "".to_string(),
+            data: Some(data),
+        }
+    }
+
+    /// 构造一个成功的简单响应对象，不带任何消息。
+    ///
+    /// returns: `TypedApiResp<T>` 返回成功响应。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::TypedApiResp;
+    /// let resp: TypedApiResp<String> = TypedApiResp::suc();
+    /// ```
+    pub fn suc() -> TypedApiResp<T> {
+        TypedApiResp {
+            success: true,
+            code: default_success_code(),
+            message: "".to_string(),
+            data: None,
+        }
+    }
+
+    /// 构造一个失败的响应对象。
+    ///
+    /// # Arguments
+    ///
+    /// * `code`: 失败代码。根据具体的业务接口约定取值列表。
+    /// * `message`: 失败信息。
+    ///
+    /// returns: `TypedApiResp<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::TypedApiResp;
+    /// let resp: TypedApiResp<String> = TypedApiResp::error(-1, String::from("查询信息失败，原因:..."));
+    /// ```
+    pub fn error(code: i32, message: String) -> TypedApiResp<T> {
+        TypedApiResp {
+            success: false,
+            code,
+            message,
+            data: None,
+        }
+    }
+}
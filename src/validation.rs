@@ -0,0 +1,51 @@
+//! 表单校验失败的响应数据结构。
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Serialize, Deserialize};
+
+use crate::ApiResp;
+
+/// 字段校验错误，作为校验失败响应的`data`。
+///
+/// 序列化结构示例：
+/// ```json
+/// {"errors": {"age": "年龄必须大于0", "name": "姓名不能为空"}}
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldErrors {
+    /// 字段名与错误信息的对照，按字段名排序。
+    pub errors: BTreeMap<String, String>,
+}
+
+impl ApiResp {
+    /// 构造一个字段校验失败的响应对象，一次返回多个字段的错误信息。
+    ///
+    /// # Arguments
+    ///
+    /// * `code`: 失败代码。
+    /// * `errors`: 字段名与错误信息，同一字段出现多次时保留最后一条。
+    ///
+    /// returns: ApiResp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_resp::{ApiResp, FieldErrors};
+    /// let resp = ApiResp::validation_error(400, vec![
+    ///     ("name".to_string(), "姓名不能为空".to_string()),
+    ///     ("age".to_string(), "年龄必须大于0".to_string()),
+    /// ]);
+    /// assert!(!resp.is_success());
+    /// let fields: FieldErrors = resp.get_data_as().unwrap().unwrap();
+    /// assert_eq!(fields.errors["name"], "姓名不能为空");
+    /// ```
+    pub fn validation_error(code: i32, errors: Vec<(String, String)>) -> ApiResp {
+        let fields = FieldErrors {
+            errors: errors.into_iter().collect(),
+        };
+        let mut resp = ApiResp::error(code, String::from("参数校验未通过"));
+        resp.data = serde_json::to_value(fields).ok();
+        resp
+    }
+}
@@ -0,0 +1,33 @@
+//! 为`warp` 0.4框架提供的响应集成，需启用`warp`特性。
+//!
+//! 启用后`ApiResp`可直接作为`warp`过滤器的返回值，响应体为JSON字符串，`Content-Type`为`application/json`。
+//! 借助`warp`为`Result<T, E>`提供的`Reply`实现，`Result<ApiResp, ApiResp>`同样可以作为返回值。
+use warp::http::header::{HeaderValue, CONTENT_TYPE};
+use warp::reply::{Reply, Response};
+
+use crate::ApiResp;
+
+impl Reply for ApiResp {
+    fn into_response(self) -> Response {
+        let mut res = self.to_json().into_response();
+        res.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use warp::http::StatusCode;
+    use super::*;
+
+    #[test]
+    fn test_into_response() {
+        let resp = ApiResp::suc().into_response();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers()[CONTENT_TYPE], "application/json");
+
+        let r: Result<ApiResp, ApiResp> = Err(ApiResp::error(-1, "交易出错了".to_string()));
+        let resp = r.into_response();
+        assert_eq!(resp.headers()[CONTENT_TYPE], "application/json");
+    }
+}